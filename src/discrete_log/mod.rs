@@ -0,0 +1,160 @@
+//! Discrete logarithms in the multiplicative group of a finite field.
+//!
+//! Every function here solves `base^x = target` for the least non-negative `x`, returning `None` when `target` is
+//! not a power of `base`. Since the multiplicative group of a finite field is cyclic, `target` is a power of `base`
+//! exactly when `target^n = 1` for the order `n` of `base`.
+
+use std::{collections::HashMap, hash::{DefaultHasher, Hasher}, ops::{Add, Div, Mul, Neg, Sub}};
+
+use crate::{tools::{factorize, mod_pow_u128, mul_mod}, FiniteField};
+
+/// Prime-order subgroups up to this size are solved by baby-step giant-step, whose table then has at most a million
+/// entries. Larger ones use Pollard's rho, which needs constant memory.
+const BABY_STEP_GIANT_STEP_LIMIT: u128 = 1 << 40;
+
+/// The discrete logarithm of `target` to the base `base`, i.e. the least `x` with `base^x = target`.
+///
+/// This runs Pohlig-Hellman over the factorisation of the multiplicative order of `base`.
+pub fn discrete_log<F: FiniteField>(base: &F, target: &F) -> Option<u128>
+where
+    for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+{
+    pohlig_hellman(base, target, base.multiplicative_order()?)
+}
+
+/// Solves `base^x = target` given the multiplicative order `order` of `base`, by reducing to the subgroups of prime
+/// power order with Pohlig-Hellman and recombining with the Chinese remainder theorem.
+pub fn pohlig_hellman<F: FiniteField>(base: &F, target: &F, order: u128) -> Option<u128>
+where
+    for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+{
+    if target.pow(order) != F::one() {
+        return None;
+    }
+    let mut x = 0;
+    let mut modulus = 1;
+    for (prime, exponent) in factorize(order) {
+        let prime_power = prime.pow(exponent);
+        // Work in the subgroup of order prime^exponent, recovering the logarithm one base-`prime` digit at a time.
+        let sub_base = base.pow(order / prime_power);
+        let sub_target = target.pow(order / prime_power);
+        let gamma = sub_base.pow(prime_power / prime);
+        let mut digits = 0;
+        let mut place = 1;
+        for _ in 0..exponent {
+            let mut shifted = sub_target;
+            shifted /= &sub_base.pow(digits);
+            let h = shifted.pow(prime_power / (place * prime));
+            digits += prime_log(&gamma, &h, prime)? * place;
+            place *= prime;
+        }
+        x = crt(x, modulus, digits, prime_power, prime, exponent);
+        modulus *= prime_power;
+    }
+    Some(x)
+}
+
+/// The discrete logarithm in a subgroup of prime order, choosing an algorithm by size.
+fn prime_log<F: FiniteField>(base: &F, target: &F, order: u128) -> Option<u128>
+where
+    for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+{
+    if order <= BABY_STEP_GIANT_STEP_LIMIT {
+        baby_step_giant_step(base, target, order)
+    } else {
+        pollard_rho(base, target, order)
+    }
+}
+
+/// Combines `x = a mod m` and `x = b mod prime^exponent` (with `m` coprime to `prime`) into `x mod m * prime^exponent`.
+fn crt(a: u128, m: u128, b: u128, prime_power: u128, prime: u128, exponent: u32) -> u128 {
+    // m^(phi(prime^exponent) - 1) is the inverse of m modulo prime^exponent.
+    let totient = prime.pow(exponent - 1) * (prime - 1);
+    let m_inverse = mod_pow_u128(m, totient - 1, prime_power);
+    let difference = (b + prime_power - a % prime_power) % prime_power;
+    a + m * mul_mod(difference, m_inverse, prime_power)
+}
+
+/// Shanks' baby-step giant-step algorithm for `base^x = target` with `0 <= x < order`.
+///
+/// Uses `O(sqrt(order))` time and memory, where `order` is any upper bound on the logarithm, such as the order of
+/// `base`.
+pub fn baby_step_giant_step<F: FiniteField>(base: &F, target: &F, order: u128) -> Option<u128>
+where
+    for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+{
+    let m = order.isqrt() + 1;
+    let mut baby_steps = HashMap::new();
+    let mut power = F::one();
+    for j in 0..m {
+        baby_steps.entry(power).or_insert(j);
+        power *= base;
+    }
+    let mut giant_step = F::one();
+    giant_step /= &base.pow(m);
+    let mut gamma = *target;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * m + j);
+        }
+        gamma *= &giant_step;
+    }
+    None
+}
+
+/// Pollard's rho algorithm for `base^x = target`, where `order` is the multiplicative order of `base` and must be
+/// prime.
+///
+/// Walks `base^a target^b` pseudo-randomly until it finds a collision, using `O(sqrt(order))` expected time and
+/// constant memory.
+pub fn pollard_rho<F: FiniteField>(base: &F, target: &F, order: u128) -> Option<u128>
+where
+    for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+{
+    if order < 4 {
+        return baby_step_giant_step(base, target, order);
+    }
+    // One step of the walk, partitioning the group into three classes by hash.
+    let step = |(mut x, a, b): (F, u128, u128)| {
+        let mut hasher = DefaultHasher::new();
+        x.hash(&mut hasher);
+        match hasher.finish() % 3 {
+            0 => {
+                x *= target;
+                (x, a, (b + 1) % order)
+            },
+            1 => {
+                x *= x;
+                (x, mul_mod(a, 2, order), mul_mod(b, 2, order))
+            },
+            _ => {
+                x *= base;
+                (x, (a + 1) % order, b)
+            },
+        }
+    };
+    // Restart from a different point whenever a collision carries no information.
+    for start in 1..order {
+        let initial = (base.pow(start), start, 0);
+        let mut tortoise = step(initial);
+        let mut hare = step(tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        // base^a1 target^b1 = base^a2 target^b2, so x (b1 - b2) = a2 - a1 mod order.
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        let db = (b1 + order - b2) % order;
+        if db == 0 {
+            continue;
+        }
+        let da = (a2 + order - a1) % order;
+        let x = mul_mod(da, mod_pow_u128(db, order - 2, order), order);
+        return if base.pow(x) == *target { Some(x) } else { None };
+    }
+    None
+}
+
+#[cfg(test)]
+mod test;
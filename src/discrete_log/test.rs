@@ -0,0 +1,66 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::IMod, Ring, X};
+
+#[test]
+fn test_discrete_log_prime_field() {
+    type F101 = IMod<101>;
+    let base = F101::new(2); // a primitive root modulo 101
+    for x in [0, 1, 5, 57, 99] {
+        let target = base.pow(x);
+        assert_eq!(discrete_log(&base, &target), Some(x));
+    }
+}
+
+#[test]
+fn test_discrete_log_outside_subgroup() {
+    type F13 = IMod<13>;
+    // 3 has order 3 modulo 13, generating {1, 3, 9}.
+    let base = F13::new(3);
+    assert_eq!(discrete_log(&base, &F13::new(9)), Some(2));
+    assert_eq!(discrete_log(&base, &F13::new(2)), None);
+    assert_eq!(discrete_log(&base, &F13::zero()), None);
+    assert_eq!(discrete_log(&F13::zero(), &F13::one()), None);
+}
+
+#[test]
+fn test_discrete_log_extension_field() {
+    type GF256 = GF<2, 8, X>;
+    let g = GF256::generator();
+    for x in [0, 3, 100, 254] {
+        assert_eq!(discrete_log(&g, &g.pow(x)), Some(x));
+    }
+    // g^5 has order 51, so only multiples of 5 are reachable from it, reduced modulo 51.
+    let h = g.pow(5);
+    assert_eq!(discrete_log(&h, &g.pow(5 * 40)), Some(40));
+    assert_eq!(discrete_log(&h, &g), None);
+}
+
+#[test]
+fn test_baby_step_giant_step() {
+    type F1009 = IMod<1009>;
+    let base = F1009::new(11); // a primitive root modulo 1009
+    let target = base.pow(777);
+    assert_eq!(baby_step_giant_step(&base, &target, 1008), Some(777));
+    assert_eq!(baby_step_giant_step(&base, &target, 700), None);
+}
+
+#[test]
+fn test_pollard_rho() {
+    // 2000303 = 2 * 1000151 + 1 is a safe prime, so every square other than 1 has prime order 1000151.
+    type F = IMod<2000303>;
+    let base = F::new(4);
+    assert_eq!(base.multiplicative_order(), Some(1000151));
+    for x in [0, 1, 123456, 1000150] {
+        assert_eq!(pollard_rho(&base, &base.pow(x), 1000151), Some(x));
+    }
+}
+
+#[test]
+fn test_pohlig_hellman_large_smooth_order() {
+    // 2^61 - 1 is prime and 2^61 - 2 = 2 * 3^2 * 5^2 * 7 * 11 * 13 * 31 * 41 * 61 * 151 * 331 * 1321 is smooth.
+    type F = IMod<{ (1 << 61) - 1 }>;
+    let base = F::new(37); // a primitive root modulo 2^61 - 1
+    assert!(base.is_primitive());
+    let x = 1_234_567_890_123_456_789;
+    assert_eq!(discrete_log(&base, &base.pow(x)), Some(x));
+}
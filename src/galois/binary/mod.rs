@@ -0,0 +1,461 @@
+//! Bit-packed binary fields `GF(2^N)` for `1 <= N <= 128`.
+//!
+//! An element is a single `u128` whose bit `i` is the coefficient of `X^i`. Addition is XOR and multiplication is a
+//! portable carry-less multiply followed by reduction modulo a sparse trinomial or pentanomial.
+//!
+//! `N` is limited to 128 because `FiniteField` enumerates elements by a `u128` index, which a larger field would
+//! overflow, and because a single word keeps the product within the two words that `carryless_mul` returns. Every `N`
+//! uses the same `u128` so that one reduction routine serves them all; the unused high bits are always zero, so
+//! narrower fields cost memory but not correctness. Fields beyond `2^128` elements are served by the generic `GF`.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{modulo_ints::IMod, tools::is_prime, FiniteField, Field, IndexedField, Indeterminate, Ring};
+
+use super::GF;
+
+/// Passing `LOW_WEIGHT` as the `TAPS` of a `BinaryGF` (which is the default) picks the conventional sparse modulus:
+/// the trinomial `X^N + X^k + 1` with least `k` if one is irreducible, and otherwise the pentanomial
+/// `X^N + X^k3 + X^k2 + X^k1 + 1` with least `k3`, then `k2`, then `k1`. This gives the AES polynomial for `N = 8`
+/// and the GCM polynomial for `N = 128`.
+pub const LOW_WEIGHT: &[u32] = &[];
+
+/// An element of `GF(2^N)` reduced modulo `X^N + X^TAPS[0] + ... + X^TAPS[k] + 1`.
+///
+/// For example `BinaryGF<8, {&[4, 3, 1]}>` is the field used by AES. `TAPS` must lie strictly between `0` and `N`
+/// and give an irreducible polynomial, which is checked at compile time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryGF<const N: usize, const TAPS: &'static [u32] = LOW_WEIGHT> {
+    bits: u128
+}
+
+pub type BinaryGF8 = BinaryGF<8>;
+pub type BinaryGF16 = BinaryGF<16>;
+pub type BinaryGF32 = BinaryGF<32>;
+pub type BinaryGF64 = BinaryGF<64>;
+pub type BinaryGF128 = BinaryGF<128>;
+
+/// The product of `a` and `b` as polynomials over `IMod<2>`, returned as its low and high 128 bits.
+pub const fn carryless_mul(a: u128, mut b: u128) -> (u128, u128) {
+    let (mut lo, mut hi) = (0u128, 0u128);
+    while b != 0 {
+        let i = b.trailing_zeros();
+        lo ^= a << i;
+        if i > 0 {
+            hi ^= a >> (128 - i);
+        }
+        b &= b - 1;
+    }
+    (lo, hi)
+}
+
+/// The square of `a` as a polynomial over `IMod<2>`, which just spreads its bits apart.
+const fn carryless_square(a: u128) -> (u128, u128) {
+    (spread(a as u64), spread((a >> 64) as u64))
+}
+
+/// Moves bit `i` of `a` to bit `2i`.
+const fn spread(a: u64) -> u128 {
+    let mut x = a as u128;
+    x = (x | (x << 32)) & 0x0000_0000_ffff_ffff_0000_0000_ffff_ffff;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff_0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333_3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555_5555_5555_5555_5555
+}
+
+/// The low `n` bits set.
+const fn mask(n: usize) -> u128 {
+    if n >= 128 { u128::MAX } else { (1 << n) - 1 }
+}
+
+/// Reduces the 256-bit polynomial `(lo, hi)` modulo `X^n + low_terms`, where `low_terms` has degree less than `n`.
+const fn reduce(mut lo: u128, mut hi: u128, n: usize, low_terms: u128) -> u128 {
+    loop {
+        // Everything from X^n upwards, shifted down to X^0.
+        let overflow = if n >= 128 { hi } else { (lo >> n) | (hi << (128 - n)) };
+        if overflow == 0 {
+            return lo & mask(n);
+        }
+        // X^n = low_terms, and the product has lower degree than (lo, hi) since low_terms has degree below n.
+        let (fold_lo, fold_hi) = carryless_mul(overflow, low_terms);
+        lo = (lo & mask(n)) ^ fold_lo;
+        hi = fold_hi;
+    }
+}
+
+/// The polynomial degree of `a`, with `-1` for zero.
+const fn degree(a: u128) -> i32 {
+    127 - a.leading_zeros() as i32
+}
+
+/// `a mod b` for polynomials over `IMod<2>` packed into `u128`s, where `b` is non-zero.
+const fn poly_rem(mut a: u128, b: u128) -> u128 {
+    let db = degree(b);
+    while degree(a) >= db {
+        a ^= b << (degree(a) - db);
+    }
+    a
+}
+
+/// gcd(`X^n + low_terms`, `a`), for `a` of degree less than `n`.
+const fn gcd_with_modulus(a: u128, n: usize, low_terms: u128) -> u128 {
+    if a == 0 {
+        // The modulus itself, which is not 1 since it has degree n > 0.
+        return 2;
+    }
+    let d = degree(a) as usize;
+    if d == 0 {
+        return 1;
+    }
+    // The modulus may need 129 bits, so take its first remainder with `reduce`, treating `a` as X^d + lower terms.
+    let (x_n_lo, x_n_hi) = if n >= 128 { (0, 1) } else { (1 << n, 0) };
+    let (mut x, mut y) = (a, reduce(x_n_lo, x_n_hi, d, a & mask(d)) ^ poly_rem(low_terms, a));
+    while y != 0 {
+        let r = poly_rem(x, y);
+        x = y;
+        y = r;
+    }
+    x
+}
+
+/// Rabin's irreducibility test for `X^n + low_terms`, as in `Polynomial::is_irreducible` but on packed bits.
+const fn is_irreducible(n: usize, low_terms: u128) -> bool {
+    if n == 0 || n > 128 || (n < 128 && low_terms >> n != 0) {
+        return false;
+    }
+    if n == 1 {
+        // Both X and X + 1 are irreducible.
+        return true;
+    }
+    if low_terms & 1 == 0 {
+        // X is a factor.
+        return false;
+    }
+    // x holds X^(2^k) mod the modulus.
+    let x = reduce(2, 0, n, low_terms);
+    let mut power = x;
+    let mut k = 1;
+    while k <= n {
+        let (lo, hi) = carryless_square(power);
+        power = reduce(lo, hi, n, low_terms);
+        // Check X^(2^(n/q)) - X is coprime to the modulus for each prime q dividing n.
+        if k < n && n.is_multiple_of(k) && is_prime((n / k) as u128) && degree(gcd_with_modulus(power ^ x, n, low_terms)) > 0 {
+            return false;
+        }
+        k += 1;
+    }
+    power == x
+}
+
+/// The low terms of the modulus chosen by `LOW_WEIGHT`.
+const fn low_weight_terms(n: usize) -> u128 {
+    if n == 1 {
+        return 1;
+    }
+    // By Swan's theorem no trinomial of degree divisible by 8 is irreducible.
+    let mut k = if n.is_multiple_of(8) { n } else { 1 };
+    while k < n {
+        let low_terms = 1 | (1 << k);
+        if is_irreducible(n, low_terms) {
+            return low_terms;
+        }
+        k += 1;
+    }
+    let mut k3 = 3;
+    while k3 < n {
+        let mut k2 = 2;
+        while k2 < k3 {
+            let mut k1 = 1;
+            while k1 < k2 {
+                let low_terms = 1 | (1 << k1) | (1 << k2) | (1 << k3);
+                if is_irreducible(n, low_terms) {
+                    return low_terms;
+                }
+                k1 += 1;
+            }
+            k2 += 1;
+        }
+        k3 += 1;
+    }
+    panic!("No irreducible trinomial or pentanomial of degree N exists")
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> BinaryGF<N, TAPS> {
+    /// The modulus minus its leading term `X^N`, as packed bits.
+    const LOW_TERMS: u128 = {
+        assert!(N >= 1 && N <= 128, "BinaryGF<N> requires 1 <= N <= 128");
+        if TAPS.is_empty() {
+            low_weight_terms(N)
+        } else {
+            let mut low_terms = 1;
+            let mut i = 0;
+            while i < TAPS.len() {
+                assert!(TAPS[i] > 0 && (TAPS[i] as usize) < N, "The taps of BinaryGF<N> must lie strictly between 0 and N");
+                low_terms |= 1 << TAPS[i];
+                i += 1;
+            }
+            assert!(is_irreducible(N, low_terms), "The modulus of BinaryGF<N> must be irreducible");
+            low_terms
+        }
+    };
+
+    /// The element whose coefficient of `X^i` is bit `i` of `bits`, reduced modulo the field's polynomial.
+    pub fn new(bits: u128) -> Self {
+        Self {
+            bits: reduce(bits, 0, N, Self::LOW_TERMS)
+        }
+    }
+
+    /// The packed coefficients of this element.
+    pub fn bits(&self) -> u128 {
+        self.bits
+    }
+
+    /// The exponents of the non-zero terms of the modulus below `X^N`, in decreasing order. The last is always zero.
+    pub fn taps() -> Vec<u32> {
+        (0..N as u32).rev().filter(|&i| (Self::LOW_TERMS >> i) & 1 == 1).collect()
+    }
+
+    /// Multiplicative inverse as `self^(2^N - 2)`, computed with `N - 1` squarings.
+    pub fn inverse(&self) -> Option<Self> {
+        if self.bits == 0 {
+            return None;
+        }
+        // 2^N - 2 = 2 + 4 + ... + 2^(N-1)
+        let mut result = Self::one();
+        let mut square = *self;
+        for _ in 1..N {
+            square *= square;
+            result *= square;
+        }
+        Some(result)
+    }
+}
+
+/// Whether `GF<2, N, _, MODULUS>` and `BinaryGF<N, _>` (with modulus coefficients `coeffs` and `low_terms`) are
+/// built on the same polynomial.
+const fn same_modulus(coeffs: &[u64], n: usize, low_terms: u128) -> bool {
+    if coeffs.len() != n + 1 || coeffs[n] % 2 != 1 {
+        return false;
+    }
+    let mut i = 0;
+    while i < n {
+        if (coeffs[i] % 2) as u128 != (low_terms >> i) & 1 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<const N: usize, const TAPS: &'static [u32], const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> From<BinaryGF<N, TAPS>> for GF<2, N, SYMBOL, MODULUS> {
+    /// Fails to compile unless both fields use the same modulus.
+    fn from(value: BinaryGF<N, TAPS>) -> Self {
+        const { assert!(same_modulus(GF::<2, N, SYMBOL, MODULUS>::COEFFS, N, BinaryGF::<N, TAPS>::LOW_TERMS), "Converting between GF and BinaryGF requires the same modulus") };
+        // The modulus is the same irreducible polynomial checked for BinaryGF, so skip the check in `GF::new`.
+        GF {
+            val: std::array::from_fn(|i| IMod::new((value.bits >> i) & 1))
+        }
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32], const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> From<GF<2, N, SYMBOL, MODULUS>> for BinaryGF<N, TAPS> {
+    /// Fails to compile unless both fields use the same modulus.
+    fn from(value: GF<2, N, SYMBOL, MODULUS>) -> Self {
+        const { assert!(same_modulus(GF::<2, N, SYMBOL, MODULUS>::COEFFS, N, BinaryGF::<N, TAPS>::LOW_TERMS), "Converting between GF and BinaryGF requires the same modulus") };
+        Self {
+            bits: value.coefficients().iter().enumerate().fold(0, |bits, (i, x)| bits | (x.value() << i))
+        }
+    }
+}
+
+// Add implementation
+impl<const N: usize, const TAPS: &'static [u32]> Add for BinaryGF<N, TAPS> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            bits: self.bits ^ rhs.bits
+        }
+    }
+}
+
+// Mul implementation
+impl<const N: usize, const TAPS: &'static [u32]> Mul for BinaryGF<N, TAPS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (lo, hi) = carryless_mul(self.bits, rhs.bits);
+        Self {
+            bits: reduce(lo, hi, N, Self::LOW_TERMS)
+        }
+    }
+}
+
+// Sub implementation
+impl<const N: usize, const TAPS: &'static [u32]> Sub for BinaryGF<N, TAPS> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Subtraction and addition coincide in characteristic 2.
+        self + rhs
+    }
+}
+
+// Neg implementation
+impl<const N: usize, const TAPS: &'static [u32]> Neg for BinaryGF<N, TAPS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+// Div implementation
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const N: usize, const TAPS: &'static [u32]> Div for BinaryGF<N, TAPS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Attempted to divide by a non-unit")
+    }
+}
+
+// AddAssign implementations
+impl<const N: usize, const TAPS: &'static [u32]> AddAssign for BinaryGF<N, TAPS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> AddAssign<&BinaryGF<N, TAPS>> for BinaryGF<N, TAPS> {
+    fn add_assign(&mut self, rhs: &BinaryGF<N, TAPS>) {
+        *self = *self + *rhs;
+    }
+}
+
+// SubAssign implementations
+impl<const N: usize, const TAPS: &'static [u32]> SubAssign for BinaryGF<N, TAPS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> SubAssign<&BinaryGF<N, TAPS>> for BinaryGF<N, TAPS> {
+    fn sub_assign(&mut self, rhs: &BinaryGF<N, TAPS>) {
+        *self = *self - *rhs;
+    }
+}
+
+// MulAssign implementations
+impl<const N: usize, const TAPS: &'static [u32]> MulAssign for BinaryGF<N, TAPS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> MulAssign<&BinaryGF<N, TAPS>> for BinaryGF<N, TAPS> {
+    fn mul_assign(&mut self, rhs: &BinaryGF<N, TAPS>) {
+        *self = *self * *rhs;
+    }
+}
+
+// DivAssign implementations
+impl<const N: usize, const TAPS: &'static [u32]> DivAssign for BinaryGF<N, TAPS> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> DivAssign<&BinaryGF<N, TAPS>> for BinaryGF<N, TAPS> {
+    fn div_assign(&mut self, rhs: &BinaryGF<N, TAPS>) {
+        *self = *self / *rhs;
+    }
+}
+
+// Reference operations for Ring trait bounds
+impl<'b, const N: usize, const TAPS: &'static [u32]> Add<&'b BinaryGF<N, TAPS>> for &BinaryGF<N, TAPS> {
+    type Output = BinaryGF<N, TAPS>;
+
+    fn add(self, rhs: &'b BinaryGF<N, TAPS>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'b, const N: usize, const TAPS: &'static [u32]> Mul<&'b BinaryGF<N, TAPS>> for &BinaryGF<N, TAPS> {
+    type Output = BinaryGF<N, TAPS>;
+
+    fn mul(self, rhs: &'b BinaryGF<N, TAPS>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'b, const N: usize, const TAPS: &'static [u32]> Sub<&'b BinaryGF<N, TAPS>> for &BinaryGF<N, TAPS> {
+    type Output = BinaryGF<N, TAPS>;
+
+    fn sub(self, rhs: &'b BinaryGF<N, TAPS>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> Neg for &BinaryGF<N, TAPS> {
+    type Output = BinaryGF<N, TAPS>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<'b, const N: usize, const TAPS: &'static [u32]> Div<&'b BinaryGF<N, TAPS>> for &BinaryGF<N, TAPS> {
+    type Output = BinaryGF<N, TAPS>;
+
+    fn div(self, rhs: &'b BinaryGF<N, TAPS>) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> Ring for BinaryGF<N, TAPS> {
+    fn zero() -> Self {
+        Self {
+            bits: 0
+        }
+    }
+
+    fn one() -> Self {
+        Self {
+            bits: 1
+        }
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> Field for BinaryGF<N, TAPS> {
+
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> FiniteField for BinaryGF<N, TAPS> {
+    /// Panics for `N = 128`, since `2^128` does not fit in a `u128`; `group_order()` does not.
+    fn size() -> u128 {
+        1u128.checked_shl(N as u32).filter(|&size| size != 0).expect("2^N must fit in a u128")
+    }
+
+    fn group_order() -> u128 {
+        mask(N)
+    }
+}
+
+impl<const N: usize, const TAPS: &'static [u32]> IndexedField for BinaryGF<N, TAPS> {
+    fn from_index(index: u128) -> Self {
+        Self::new(index)
+    }
+
+    fn index(&self) -> u128 {
+        self.bits
+    }
+}
+
+#[cfg(test)]
+mod test;
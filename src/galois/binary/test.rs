@@ -0,0 +1,182 @@
+use super::*;
+use crate::{polynomial::Polynomial, X};
+
+// GF(2^8) as used by AES, on X^8 + X^4 + X^3 + X + 1.
+type Aes = BinaryGF<8, {&[4, 3, 1]}>;
+type AesGF = GF<2, 8, X, {&[1, 1, 0, 1, 1, 0, 0, 0, 1]}>;
+
+/// The GCM polynomial `X^128 + X^7 + X^2 + X + 1`, the default modulus of `BinaryGF128`.
+const GCM: &[u64] = &{
+    let mut modulus = [0; 129];
+    modulus[0] = 1;
+    modulus[1] = 1;
+    modulus[2] = 1;
+    modulus[7] = 1;
+    modulus[128] = 1;
+    modulus
+};
+type GcmGF = GF<2, 128, X, GCM>;
+
+#[test]
+fn test_carryless_mul() {
+    // (X + 1)^2 = X^2 + 1
+    assert_eq!(carryless_mul(0b11, 0b11), (0b101, 0));
+    // X^127 * X^2 = X^129
+    assert_eq!(carryless_mul(1 << 127, 0b100), (0, 0b10));
+    assert_eq!(carryless_mul(u128::MAX, 1), (u128::MAX, 0));
+}
+
+#[test]
+fn test_binary_gf_default_moduli() {
+    // The standard low-weight choices: AES, X^16 + X^5 + X^3 + X + 1, X^32 + X^7 + X^3 + X^2 + 1,
+    // X^64 + X^4 + X^3 + X + 1 and the GCM polynomial X^128 + X^7 + X^2 + X + 1.
+    assert_eq!(BinaryGF8::taps(), vec![4, 3, 1, 0]);
+    assert_eq!(BinaryGF16::taps(), vec![5, 3, 1, 0]);
+    assert_eq!(BinaryGF32::taps(), vec![7, 3, 2, 0]);
+    assert_eq!(BinaryGF64::taps(), vec![4, 3, 1, 0]);
+    assert_eq!(BinaryGF128::taps(), vec![7, 2, 1, 0]);
+    // Trinomials are preferred when one exists.
+    assert_eq!(BinaryGF::<7>::taps(), vec![1, 0]);
+    assert_eq!(BinaryGF::<1>::taps(), vec![0]);
+}
+
+#[test]
+fn test_binary_gf_aes_multiplication() {
+    // The worked example from FIPS-197: {57} * {83} = {c1}.
+    assert_eq!(Aes::new(0x57) * Aes::new(0x83), Aes::new(0xc1));
+    assert_eq!(Aes::new(0x57) + Aes::new(0x83), Aes::new(0xd4));
+    // Bits above X^7 are reduced on construction: X^8 = X^4 + X^3 + X + 1.
+    assert_eq!(Aes::new(0x100), Aes::new(0x1b));
+}
+
+#[test]
+fn test_binary_gf_matches_gf() {
+    for a in 0..256 {
+        let x = Aes::new(a);
+        assert_eq!(Aes::from(AesGF::from(x)), x);
+        for b in [0x01, 0x02, 0x53, 0xca, 0xff] {
+            let y = Aes::new(b);
+            assert_eq!(AesGF::from(x * y), AesGF::from(x) * AesGF::from(y));
+            assert_eq!(AesGF::from(x + y), AesGF::from(x) + AesGF::from(y));
+        }
+    }
+    let x: AesGF = GF::from_poly(&Polynomial::indeterminant_power(1));
+    assert_eq!(Aes::from(x), Aes::new(2));
+    // Both enumerate the field by coefficient bits.
+    for i in [0, 1, 2, 0x53, 0xff] {
+        assert_eq!(AesGF::from(Aes::from_index(i)), AesGF::from_index(i));
+        assert_eq!(IndexedField::index(&Aes::from_index(i)), i);
+    }
+}
+
+#[test]
+fn test_binary_gf_inverse() {
+    assert!(Aes::zero().inverse().is_none());
+    // {53} and {ca} are inverses in the AES field.
+    assert_eq!(Aes::new(0x53).inverse(), Some(Aes::new(0xca)));
+    for a in 1..256 {
+        let x = Aes::new(a);
+        assert_eq!(x * x.inverse().unwrap(), Aes::one());
+    }
+}
+
+#[test]
+fn test_binary_gf_large_fields() {
+    // X^128 = X^7 + X^2 + X + 1 in the GCM field.
+    let x = BinaryGF128::new(2);
+    let mut power = BinaryGF128::one();
+    for _ in 0..128 {
+        power *= x;
+    }
+    assert_eq!(power, BinaryGF128::new(0x87));
+
+    // a^(2^N) = a, checked with N squarings.
+    let a = BinaryGF128::new(0x0123_4567_89ab_cdef_fedc_ba98_7654_3210);
+    let mut frobenius = a;
+    for _ in 0..128 {
+        frobenius *= frobenius;
+    }
+    assert_eq!(frobenius, a);
+    assert_eq!(a * a.inverse().unwrap(), BinaryGF128::one());
+    let b = BinaryGF64::new(0xdead_beef_cafe_f00d);
+    assert_eq!((b / b.inverse().unwrap()) * b.inverse().unwrap().pow(2), BinaryGF64::one());
+}
+
+#[test]
+fn test_binary_gf_128_group_order() {
+    // 2^128 does not fit in a u128, but the group order and everything built on it does.
+    assert_eq!(BinaryGF128::group_order(), u128::MAX);
+    assert_eq!(BinaryGF8::group_order(), 255);
+    assert_eq!(BinaryGF128::one().multiplicative_order(), Some(1));
+    // 2^128 - 1 = (2^64 - 1)(2^64 + 1), and GF(2^64) is the subfield fixed by x -> x^(2^64).
+    let x = BinaryGF128::new(0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    let order = x.multiplicative_order().unwrap();
+    assert_eq!(x.pow(order), BinaryGF128::one());
+    assert!(u128::MAX.is_multiple_of(order));
+    assert!(!x.pow(1 << 64 | 1).is_primitive());
+    assert!(u64::MAX.is_multiple_of(x.pow(1 << 64 | 1).multiplicative_order().unwrap() as u64));
+
+    let (a, b) = (BinaryGF128::new(3), BinaryGF128::new(1 << 100 | 7));
+    let f: Polynomial<BinaryGF128, X> = &Polynomial::from_coeffs(&[a, BinaryGF128::one()]) * &Polynomial::from_coeffs(&[b, BinaryGF128::one()]);
+    let mut expected = vec![(a, 1), (b, 1)];
+    expected.sort_by_key(|(x, _)| x.index());
+    assert_eq!(f.roots(), expected);
+}
+
+#[test]
+fn test_gf_128_group_order() {
+    // The generic GF(2^128) never forms 2^128 either, and agrees with the bit-packed field on the same modulus.
+    assert_eq!(GcmGF::group_order(), u128::MAX);
+    let bits = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321;
+    let x = GcmGF::from_index(bits);
+    assert_eq!(BinaryGF128::from(x), BinaryGF128::new(bits));
+    // An element of the subfield GF(2^8), whose order divides 255, has its logarithms found by discrete_log.
+    let h = x.pow(u128::MAX / 255);
+    let order = h.multiplicative_order().unwrap();
+    assert!(255u128.is_multiple_of(order));
+    assert_eq!(order, BinaryGF128::from(h).multiplicative_order().unwrap());
+    assert_eq!(crate::discrete_log::discrete_log(&h, &h.pow(7)), Some(7 % order));
+
+    let (a, b) = (GcmGF::from_index(3), GcmGF::from_index(1 << 100 | 7));
+    let f: Polynomial<GcmGF, X> = &Polynomial::from_coeffs(&[a, GcmGF::one()]) * &Polynomial::from_coeffs(&[b, GcmGF::one()]);
+    let mut expected = vec![(a, 1), (b, 1)];
+    expected.sort_by_key(|(x, _)| x.index());
+    assert_eq!(f.roots(), expected);
+}
+
+#[test]
+fn test_binary_gf_odd_degree() {
+    // GF(2^13) on a pentanomial, since no trinomial of degree 13 is irreducible.
+    type GF8192 = BinaryGF<13>;
+    assert_eq!(GF8192::taps().len(), 4);
+    assert_eq!(GF8192::size(), 8192);
+    let g = GF8192::new(2);
+    // 2^13 - 1 is prime, so every element other than 0 and 1 is primitive.
+    assert_eq!(g.multiplicative_order(), Some(8191));
+    assert_eq!(g.pow(8191), GF8192::one());
+
+    let mut x = GF8192::new(0x1abc);
+    x -= GF8192::new(0x1abc);
+    assert_eq!(x, GF8192::zero());
+    assert_eq!(-GF8192::new(5), GF8192::new(5));
+}
+
+#[test]
+fn test_binary_gf_is_irreducible() {
+    // X^2 + X + 1 is irreducible, X^2 + 1 = (X + 1)^2 is not.
+    assert!(is_irreducible(2, 0b11));
+    assert!(!is_irreducible(2, 0b01));
+    // X^4 + X^2 + 1 = (X^2 + X + 1)^2, which only the gcd step catches.
+    assert!(!is_irreducible(4, 0b101));
+    assert!(is_irreducible(4, 0b011));
+    // Of the degree 6 trinomials, X^6 + X + 1 and X^6 + X^3 + 1 are irreducible but X^6 + X^2 + 1 is not.
+    assert!(is_irreducible(6, 0b1001));
+    assert!(is_irreducible(6, 0b11));
+    assert!(!is_irreducible(6, 0b101));
+}
+
+#[test]
+#[should_panic(expected = "Attempted to divide by a non-unit")]
+fn test_binary_gf_division_by_zero() {
+    let _result = Aes::one() / Aes::zero();
+}
@@ -0,0 +1,388 @@
+//! Conway polynomials, the standard choice of modulus for `GF(P^N)` shared by SageMath, Magma and GAP.
+//!
+//! The Conway polynomial `C(P, N)` is the least monic primitive polynomial of degree `N` over `IMod<P>` which is
+//! compatible with `C(P, M)` for every `M` dividing `N`, in the sense that `C(P, M)(X^((P^N - 1)/(P^M - 1)))` is
+//! divisible by `C(P, N)`. Polynomials are ordered by writing them as
+//! `X^N - a[N-1] X^(N-1) + a[N-2] X^(N-2) - ... + (-1)^N a[0]` and comparing `(a[N-1], ..., a[0])`
+//! lexicographically, with each `a[i]` taken in `0..P`.
+
+use crate::{modulo_ints::IMod, polynomial::Polynomial, Indeterminate, Ring};
+
+/// Tabulated Conway polynomials as `(P, N, coefficients)`, where `coefficients[i]` is the coefficient of `X^i`.
+///
+/// Every prime below 200 is covered for `N <= 3`, smaller primes up to larger degrees, and `P = 2` up to `N = 29`.
+/// These are the only pairs for which `GF<P, N, SYMBOL>` compiles without an explicit `MODULUS`.
+pub const CONWAY_POLYNOMIALS: &[(u64, usize, &[u64])] = &[
+    (2, 1, &[1, 1]),
+    (2, 2, &[1, 1, 1]),
+    (2, 3, &[1, 1, 0, 1]),
+    (2, 4, &[1, 1, 0, 0, 1]),
+    (2, 5, &[1, 0, 1, 0, 0, 1]),
+    (2, 6, &[1, 1, 0, 1, 1, 0, 1]),
+    (2, 7, &[1, 1, 0, 0, 0, 0, 0, 1]),
+    (2, 8, &[1, 0, 1, 1, 1, 0, 0, 0, 1]),
+    (2, 9, &[1, 0, 0, 0, 1, 0, 0, 0, 0, 1]),
+    (2, 10, &[1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1]),
+    (2, 11, &[1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 12, &[1, 1, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 1]),
+    (2, 13, &[1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 14, &[1, 0, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 15, &[1, 0, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 16, &[1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 17, &[1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 18, &[1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 1]),
+    (2, 19, &[1, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 20, &[1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 21, &[1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 22, &[1, 0, 0, 0, 0, 1, 1, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 23, &[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 24, &[1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 25, &[1, 0, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 26, &[1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 27, &[1, 0, 1, 1, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 28, &[1, 0, 1, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (2, 29, &[1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+
+    (3, 1, &[1, 1]),
+    (3, 2, &[2, 2, 1]),
+    (3, 3, &[1, 2, 0, 1]),
+    (3, 4, &[2, 0, 0, 2, 1]),
+    (3, 5, &[1, 2, 0, 0, 0, 1]),
+    (3, 6, &[2, 2, 1, 0, 2, 0, 1]),
+    (3, 7, &[1, 0, 2, 0, 0, 0, 0, 1]),
+    (3, 8, &[2, 2, 2, 0, 1, 2, 0, 0, 1]),
+    (3, 9, &[1, 1, 2, 2, 0, 0, 0, 0, 0, 1]),
+    (3, 10, &[2, 1, 0, 0, 2, 2, 2, 0, 0, 0, 1]),
+    (3, 11, &[1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 12, &[2, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 1]),
+    (3, 13, &[1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 14, &[2, 0, 1, 2, 0, 1, 2, 1, 1, 2, 0, 0, 0, 0, 1]),
+    (3, 15, &[1, 1, 2, 0, 0, 1, 0, 0, 2, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 16, &[2, 1, 2, 2, 2, 0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 17, &[1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 18, &[2, 0, 2, 0, 2, 1, 2, 0, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (3, 19, &[1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+
+    (5, 1, &[3, 1]),
+    (5, 2, &[2, 4, 1]),
+    (5, 3, &[3, 3, 0, 1]),
+    (5, 4, &[2, 4, 4, 0, 1]),
+    (5, 5, &[3, 4, 0, 0, 0, 1]),
+    (5, 6, &[2, 0, 1, 4, 1, 0, 1]),
+    (5, 7, &[3, 3, 0, 0, 0, 0, 0, 1]),
+    (5, 8, &[2, 4, 3, 0, 1, 0, 0, 0, 1]),
+    (5, 9, &[3, 1, 0, 2, 0, 0, 0, 0, 0, 1]),
+    (5, 10, &[2, 1, 4, 2, 3, 3, 0, 0, 0, 0, 1]),
+    (5, 11, &[3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]),
+    (5, 12, &[2, 2, 3, 4, 4, 0, 1, 1, 0, 0, 0, 0, 1]),
+
+    (7, 1, &[4, 1]),
+    (7, 2, &[3, 6, 1]),
+    (7, 3, &[4, 0, 6, 1]),
+    (7, 4, &[3, 4, 5, 0, 1]),
+    (7, 5, &[4, 1, 0, 0, 0, 1]),
+    (7, 6, &[3, 6, 4, 5, 1, 0, 1]),
+    (7, 7, &[4, 6, 0, 0, 0, 0, 0, 1]),
+    (7, 8, &[3, 2, 6, 4, 0, 0, 0, 0, 1]),
+    (7, 9, &[4, 6, 0, 1, 6, 0, 0, 0, 0, 1]),
+    (7, 10, &[3, 3, 2, 1, 4, 1, 1, 0, 0, 0, 1]),
+
+    (11, 1, &[9, 1]),
+    (11, 2, &[2, 7, 1]),
+    (11, 3, &[9, 2, 0, 1]),
+    (11, 4, &[2, 10, 8, 0, 1]),
+    (11, 5, &[9, 0, 10, 0, 0, 1]),
+    (11, 6, &[2, 7, 6, 4, 3, 0, 1]),
+    (11, 7, &[9, 4, 0, 0, 0, 0, 0, 1]),
+    (11, 8, &[2, 7, 1, 7, 7, 0, 0, 0, 1]),
+
+    (13, 1, &[11, 1]),
+    (13, 2, &[2, 12, 1]),
+    (13, 3, &[11, 2, 0, 1]),
+    (13, 4, &[2, 12, 3, 0, 1]),
+    (13, 5, &[11, 4, 0, 0, 0, 1]),
+    (13, 6, &[2, 11, 11, 10, 0, 0, 1]),
+    (13, 7, &[11, 3, 0, 0, 0, 0, 0, 1]),
+    (13, 8, &[2, 3, 2, 12, 8, 0, 0, 0, 1]),
+
+    (17, 1, &[14, 1]),
+    (17, 2, &[3, 16, 1]),
+    (17, 3, &[14, 1, 0, 1]),
+    (17, 4, &[3, 10, 7, 0, 1]),
+    (17, 5, &[14, 1, 0, 0, 0, 1]),
+    (17, 6, &[3, 3, 10, 0, 2, 0, 1]),
+    (17, 7, &[14, 12, 0, 0, 0, 0, 0, 1]),
+
+    (19, 1, &[17, 1]),
+    (19, 2, &[2, 18, 1]),
+    (19, 3, &[17, 4, 0, 1]),
+    (19, 4, &[2, 11, 2, 0, 1]),
+    (19, 5, &[17, 5, 0, 0, 0, 1]),
+    (19, 6, &[2, 6, 17, 17, 0, 0, 1]),
+    (19, 7, &[17, 6, 0, 0, 0, 0, 0, 1]),
+
+    (23, 1, &[18, 1]),
+    (23, 2, &[5, 21, 1]),
+    (23, 3, &[18, 2, 0, 1]),
+    (23, 4, &[5, 19, 3, 0, 1]),
+    (23, 5, &[18, 3, 0, 0, 0, 1]),
+    (23, 6, &[5, 1, 9, 9, 1, 0, 1]),
+
+    (29, 1, &[27, 1]),
+    (29, 2, &[2, 24, 1]),
+    (29, 3, &[27, 2, 0, 1]),
+    (29, 4, &[2, 15, 2, 0, 1]),
+    (29, 5, &[27, 3, 0, 0, 0, 1]),
+    (29, 6, &[2, 13, 17, 25, 1, 0, 1]),
+
+    (31, 1, &[28, 1]),
+    (31, 2, &[3, 29, 1]),
+    (31, 3, &[28, 1, 0, 1]),
+    (31, 4, &[3, 16, 3, 0, 1]),
+    (31, 5, &[28, 7, 0, 0, 0, 1]),
+    (31, 6, &[3, 8, 16, 19, 0, 0, 1]),
+
+    (37, 1, &[35, 1]),
+    (37, 2, &[2, 33, 1]),
+    (37, 3, &[35, 6, 0, 1]),
+    (37, 4, &[2, 24, 6, 0, 1]),
+    (37, 5, &[35, 10, 0, 0, 0, 1]),
+    (37, 6, &[2, 30, 4, 35, 0, 0, 1]),
+
+    (41, 1, &[35, 1]),
+    (41, 2, &[6, 38, 1]),
+    (41, 3, &[35, 1, 0, 1]),
+    (41, 4, &[6, 23, 0, 0, 1]),
+    (41, 5, &[35, 14, 40, 0, 0, 1]),
+
+    (43, 1, &[40, 1]),
+    (43, 2, &[3, 42, 1]),
+    (43, 3, &[40, 1, 0, 1]),
+    (43, 4, &[3, 42, 5, 0, 1]),
+    (43, 5, &[40, 8, 0, 0, 0, 1]),
+
+    (47, 1, &[42, 1]),
+    (47, 2, &[5, 45, 1]),
+    (47, 3, &[42, 3, 0, 1]),
+    (47, 4, &[5, 40, 8, 0, 1]),
+    (47, 5, &[42, 1, 0, 0, 0, 1]),
+
+    (53, 1, &[51, 1]),
+    (53, 2, &[2, 49, 1]),
+    (53, 3, &[51, 3, 0, 1]),
+    (53, 4, &[2, 38, 9, 0, 1]),
+
+    (59, 1, &[57, 1]),
+    (59, 2, &[2, 58, 1]),
+    (59, 3, &[57, 5, 0, 1]),
+    (59, 4, &[2, 40, 2, 0, 1]),
+
+    (61, 1, &[59, 1]),
+    (61, 2, &[2, 60, 1]),
+    (61, 3, &[59, 7, 0, 1]),
+    (61, 4, &[2, 40, 3, 0, 1]),
+
+    (67, 1, &[65, 1]),
+    (67, 2, &[2, 63, 1]),
+    (67, 3, &[65, 6, 0, 1]),
+    (67, 4, &[2, 54, 8, 0, 1]),
+
+    (71, 1, &[64, 1]),
+    (71, 2, &[7, 69, 1]),
+    (71, 3, &[64, 4, 0, 1]),
+    (71, 4, &[7, 41, 4, 0, 1]),
+
+    (73, 1, &[68, 1]),
+    (73, 2, &[5, 70, 1]),
+    (73, 3, &[68, 2, 0, 1]),
+    (73, 4, &[5, 56, 16, 0, 1]),
+
+    (79, 1, &[76, 1]),
+    (79, 2, &[3, 78, 1]),
+    (79, 3, &[76, 9, 0, 1]),
+    (79, 4, &[3, 66, 2, 0, 1]),
+
+    (83, 1, &[81, 1]),
+    (83, 2, &[2, 82, 1]),
+    (83, 3, &[81, 3, 0, 1]),
+    (83, 4, &[2, 42, 4, 0, 1]),
+
+    (89, 1, &[86, 1]),
+    (89, 2, &[3, 82, 1]),
+    (89, 3, &[86, 3, 0, 1]),
+    (89, 4, &[3, 72, 4, 0, 1]),
+
+    (97, 1, &[92, 1]),
+    (97, 2, &[5, 96, 1]),
+    (97, 3, &[92, 9, 0, 1]),
+    (97, 4, &[5, 80, 6, 0, 1]),
+
+    (101, 1, &[99, 1]),
+    (101, 2, &[2, 97, 1]),
+    (101, 3, &[99, 3, 0, 1]),
+
+    (103, 1, &[98, 1]),
+    (103, 2, &[5, 102, 1]),
+    (103, 3, &[98, 2, 0, 1]),
+
+    (107, 1, &[105, 1]),
+    (107, 2, &[2, 103, 1]),
+    (107, 3, &[105, 5, 0, 1]),
+
+    (109, 1, &[103, 1]),
+    (109, 2, &[6, 108, 1]),
+    (109, 3, &[103, 1, 0, 1]),
+
+    (113, 1, &[110, 1]),
+    (113, 2, &[3, 101, 1]),
+    (113, 3, &[110, 8, 0, 1]),
+
+    (127, 1, &[124, 1]),
+    (127, 2, &[3, 126, 1]),
+    (127, 3, &[124, 3, 0, 1]),
+
+    (131, 1, &[129, 1]),
+    (131, 2, &[2, 127, 1]),
+    (131, 3, &[129, 3, 0, 1]),
+
+    (137, 1, &[134, 1]),
+    (137, 2, &[3, 131, 1]),
+    (137, 3, &[134, 6, 0, 1]),
+
+    (139, 1, &[137, 1]),
+    (139, 2, &[2, 138, 1]),
+    (139, 3, &[137, 6, 0, 1]),
+
+    (149, 1, &[147, 1]),
+    (149, 2, &[2, 145, 1]),
+    (149, 3, &[147, 3, 0, 1]),
+
+    (151, 1, &[145, 1]),
+    (151, 2, &[6, 149, 1]),
+    (151, 3, &[145, 1, 0, 1]),
+
+    (157, 1, &[152, 1]),
+    (157, 2, &[5, 152, 1]),
+    (157, 3, &[152, 1, 0, 1]),
+
+    (163, 1, &[161, 1]),
+    (163, 2, &[2, 159, 1]),
+    (163, 3, &[161, 7, 0, 1]),
+
+    (167, 1, &[162, 1]),
+    (167, 2, &[5, 166, 1]),
+    (167, 3, &[162, 7, 0, 1]),
+
+    (173, 1, &[171, 1]),
+    (173, 2, &[2, 169, 1]),
+    (173, 3, &[171, 2, 0, 1]),
+
+    (179, 1, &[177, 1]),
+    (179, 2, &[2, 172, 1]),
+    (179, 3, &[177, 4, 0, 1]),
+
+    (181, 1, &[179, 1]),
+    (181, 2, &[2, 177, 1]),
+    (181, 3, &[179, 6, 0, 1]),
+
+    (191, 1, &[172, 1]),
+    (191, 2, &[19, 190, 1]),
+    (191, 3, &[172, 4, 0, 1]),
+
+    (193, 1, &[188, 1]),
+    (193, 2, &[5, 192, 1]),
+    (193, 3, &[188, 1, 0, 1]),
+
+    (197, 1, &[195, 1]),
+    (197, 2, &[2, 192, 1]),
+    (197, 3, &[195, 3, 0, 1]),
+
+    (199, 1, &[196, 1]),
+    (199, 2, &[3, 193, 1]),
+    (199, 3, &[196, 1, 0, 1]),
+];
+
+/// The tabulated Conway polynomial for `GF(p^n)`, if there is one. Usable in constant expressions.
+pub const fn lookup(p: u64, n: usize) -> Option<&'static [u64]> {
+    let mut i = 0;
+    while i < CONWAY_POLYNOMIALS.len() {
+        let (q, m, coeffs) = CONWAY_POLYNOMIALS[i];
+        if q == p && m == n {
+            return Some(coeffs);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The Conway polynomial for `GF(P^n)`, taken from `CONWAY_POLYNOMIALS` when tabulated and computed otherwise.
+///
+/// Computing a missing polynomial searches every candidate of degree `n` in Conway order, so this is only feasible
+/// when `P^n` is small. The result is a runtime value and cannot serve as the `MODULUS` of a `GF` directly: to build a
+/// field on an untabulated Conway polynomial, write its coefficients out as a `&[u64]` constant.
+pub fn conway_polynomial<const P: u64, const VAR: Indeterminate>(n: usize) -> Polynomial<IMod<P>, VAR> {
+    assert!(n > 0, "Conway polynomials have positive degree");
+    if let Some(coeffs) = lookup(P, n) {
+        return to_polynomial(coeffs);
+    }
+    search(n)
+}
+
+/// Searches every monic polynomial of degree `n` in Conway order for the Conway polynomial.
+fn search<const P: u64, const VAR: Indeterminate>(n: usize) -> Polynomial<IMod<P>, VAR> {
+    // Compatibility with the maximal proper subfields implies compatibility with all of them.
+    let order = (P as u128).checked_pow(n as u32).expect("P^n must fit in a u128") - 1;
+    let subfields: Vec<_> = crate::tools::distinct_prime_factors(n as u128)
+        .into_iter()
+        .map(|q| {
+            let m = n / q as usize;
+            let sub_order = (P as u128).pow(m as u32) - 1;
+            (conway_polynomial::<P, VAR>(m), order / sub_order)
+        })
+        .collect();
+    let x = Polynomial::indeterminant_power(1);
+    // Candidates are enumerated by `a = (a[n-1], ..., a[0])` read as a base-P number.
+    for index in 0..=order {
+        let candidate = candidate::<P, VAR>(n, index);
+        let compatible = subfields.iter().all(|(sub, exponent)| {
+            let root = x.pow_mod(*exponent, &candidate);
+            evaluate_mod(sub, &root, &candidate).deg().is_none()
+        });
+        if compatible && candidate.is_primitive() {
+            return candidate;
+        }
+    }
+    unreachable!("Every finite field has a Conway polynomial")
+}
+
+fn to_polynomial<const P: u64, const VAR: Indeterminate>(coeffs: &[u64]) -> Polynomial<IMod<P>, VAR> {
+    Polynomial::from_coeffs(&coeffs.iter().map(|&x| IMod::new(x as u128)).collect::<Vec<_>>())
+}
+
+/// The monic polynomial of degree `n` at position `index` in Conway order.
+fn candidate<const P: u64, const VAR: Indeterminate>(n: usize, mut index: u128) -> Polynomial<IMod<P>, VAR> {
+    let mut coeffs = vec![IMod::zero(); n + 1];
+    coeffs[n] = IMod::one();
+    for (i, coeff) in coeffs.iter_mut().enumerate().take(n) {
+        let a = IMod::new(index % P as u128);
+        index /= P as u128;
+        *coeff = if (n - i).is_multiple_of(2) { a } else { -a };
+    }
+    Polynomial::from_coeffs(&coeffs)
+}
+
+/// `poly(at) mod modulus`, by Horner's rule.
+fn evaluate_mod<const P: u64, const VAR: Indeterminate>(
+    poly: &Polynomial<IMod<P>, VAR>,
+    at: &Polynomial<IMod<P>, VAR>,
+    modulus: &Polynomial<IMod<P>, VAR>,
+) -> Polynomial<IMod<P>, VAR> {
+    let mut out = Polynomial::zero();
+    for i in (0..=poly.deg().unwrap_or(0)).rev() {
+        out = &(&(&out * at) + &Polynomial::from_coeffs(&[poly.coeff(i)])) % modulus;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test;
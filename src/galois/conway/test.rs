@@ -0,0 +1,54 @@
+use super::*;
+use crate::X;
+
+#[test]
+fn test_search_reproduces_table() {
+    fn check<const P: u64>(max_degree: usize) {
+        for n in 1..=max_degree {
+            let expected: Polynomial<IMod<P>, X> = to_polynomial(lookup(P, n).unwrap());
+            assert_eq!(search::<P, X>(n), expected, "Conway polynomial for GF({}^{})", P, n);
+        }
+    }
+    check::<2>(8);
+    check::<3>(4);
+    check::<5>(3);
+    check::<7>(2);
+}
+
+#[test]
+fn test_table_entries_are_primitive() {
+    fn check<const P: u64>() {
+        for &(p, n, coeffs) in CONWAY_POLYNOMIALS.iter().filter(|(p, n, _)| *p == P && *n <= 6) {
+            let poly: Polynomial<IMod<P>, X> = to_polynomial(coeffs);
+            assert_eq!(poly.deg(), Some(n));
+            assert!(poly.is_primitive(), "Conway polynomial for GF({}^{})", p, n);
+        }
+    }
+    check::<2>();
+    check::<3>();
+    check::<13>();
+}
+
+#[test]
+fn test_known_conway_polynomials() {
+    // X^8 + X^4 + X^3 + X^2 + 1 for GF(2^8).
+    let expected: Polynomial<IMod<2>, X> = to_polynomial(&[1, 0, 1, 1, 1, 0, 0, 0, 1]);
+    assert_eq!(conway_polynomial::<2, X>(8), expected);
+    // X^2 + 4X + 2 for GF(25).
+    let expected: Polynomial<IMod<5>, X> = to_polynomial(&[2, 4, 1]);
+    assert_eq!(conway_polynomial::<5, X>(2), expected);
+    // X^17 + X^3 + 1 for GF(2^17).
+    let expected: Polynomial<IMod<2>, X> = to_polynomial(&[1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(conway_polynomial::<2, X>(17), expected);
+    // X^2 + 97X + 2 for GF(101^2).
+    let expected: Polynomial<IMod<101>, X> = to_polynomial(&[2, 97, 1]);
+    assert_eq!(conway_polynomial::<101, X>(2), expected);
+}
+
+#[test]
+fn test_conway_polynomial_outside_table() {
+    assert_eq!(lookup(211, 1), None);
+    // 2 is the least primitive root modulo 211, so C(211, 1) = X - 2.
+    let expected: Polynomial<IMod<211>, X> = to_polynomial(&[209, 1]);
+    assert_eq!(conway_polynomial::<211, X>(1), expected);
+}
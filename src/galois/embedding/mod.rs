@@ -0,0 +1,112 @@
+//! Embeddings of `GF(P^M)` into `GF(P^N)` for `M` dividing `N`.
+//!
+//! A field homomorphism out of `GF(P^M) = F_P[Y]/(g)` is fixed by where it sends `Y`, which must be a root of `g`.
+//! Since `g` is irreducible of degree `M`, it splits in `GF(P^N)` exactly when `M` divides `N`, and any of its roots
+//! gives an embedding; the root is found with `Polynomial::roots_in`. The image is the `F_P`-span of the powers of
+//! that root, so an element of the larger field is projected back by solving the linear system for its coordinates.
+
+use crate::{modulo_ints::IMod, Indeterminate, Ring};
+
+use super::GF;
+
+/// The embedding of the field `Small` into the field `Large` that sends the symbol of `Small` to `root()`.
+#[derive(Clone, Debug)]
+pub struct Embedding<Small, Large> {
+    root: Large,
+    /// `images[i]` is the image of `SYMBOL^i`.
+    images: Vec<Large>,
+    /// `projection[j]` is the component of the projection due to the `j`-th coordinate of `Large`.
+    projection: Vec<Small>,
+}
+
+impl<const P: u64, const M: usize, const N: usize, const SMALL: Indeterminate, const SMALL_MODULUS: &'static [u64], const LARGE: Indeterminate, const LARGE_MODULUS: &'static [u64]> Embedding<GF<P, M, SMALL, SMALL_MODULUS>, GF<P, N, LARGE, LARGE_MODULUS>> {
+    /// The embedding sending the symbol of the smaller field to the first root of its modulus in the larger field, in
+    /// the order of `IndexedField::index`. Fails to compile unless `M` divides `N`.
+    pub fn new() -> Self {
+        const { assert!(M > 0 && N.is_multiple_of(M), "GF(P^M) embeds in GF(P^N) only when M divides N") };
+        let roots = GF::<P, M, SMALL, SMALL_MODULUS>::modulus().roots_in::<GF<P, N, LARGE, LARGE_MODULUS>>();
+        Self::with_root(roots[0].0)
+    }
+
+    /// The embedding sending the symbol of the smaller field to `root`, or `None` if `root` is not a root of the
+    /// smaller field's modulus. Each of the `M` roots gives a different embedding.
+    pub fn from_root(root: GF<P, N, LARGE, LARGE_MODULUS>) -> Option<Self> {
+        const { assert!(M > 0 && N.is_multiple_of(M), "GF(P^M) embeds in GF(P^N) only when M divides N") };
+        if GF::<P, M, SMALL, SMALL_MODULUS>::modulus().eval_in(&root) != GF::zero() {
+            return None;
+        }
+        Some(Self::with_root(root))
+    }
+
+    /// The embedding sending the symbol of the smaller field to `root`, which must be a root of its modulus.
+    fn with_root(root: GF<P, N, LARGE, LARGE_MODULUS>) -> Self {
+        let images: Vec<GF<P, N, LARGE, LARGE_MODULUS>> = (0..M).scan(GF::one(), |power, _| {
+            let current = *power;
+            *power *= root;
+            Some(current)
+        }).collect();
+        // Row reduce the N x M matrix whose columns are the images, alongside the identity. As the images are
+        // independent, the first M rows of the transformed identity are then a left inverse of the matrix.
+        let mut rows: Vec<Vec<IMod<P>>> = (0..N).map(|j| {
+            let mut row: Vec<IMod<P>> = images.iter().map(|image| image.val[j]).collect();
+            row.extend((0..N).map(|k| if j == k { IMod::one() } else { IMod::zero() }));
+            row
+        }).collect();
+        for col in 0..M {
+            let pivot = (col..N).find(|&r| rows[r][col] != IMod::zero()).expect("The powers of a root of an irreducible polynomial are independent");
+            rows.swap(col, pivot);
+            let inverse = rows[col][col].inverse().expect("Pivots are non-zero");
+            for x in rows[col].iter_mut() {
+                *x *= inverse;
+            }
+            let pivot_row = rows[col].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                let factor = row[col];
+                if r != col && factor != IMod::zero() {
+                    for (x, y) in row.iter_mut().zip(&pivot_row) {
+                        *x -= factor * *y;
+                    }
+                }
+            }
+        }
+        let projection = (0..N).map(|j| GF { val: std::array::from_fn(|i| rows[i][M + j]) }).collect();
+        Self {
+            root,
+            images,
+            projection,
+        }
+    }
+
+    /// The image of the symbol of the smaller field, a root of its modulus in the larger field.
+    pub fn root(&self) -> GF<P, N, LARGE, LARGE_MODULUS> {
+        self.root
+    }
+
+    /// The image of `x` in the larger field.
+    pub fn embed(&self, x: &GF<P, M, SMALL, SMALL_MODULUS>) -> GF<P, N, LARGE, LARGE_MODULUS> {
+        x.val.iter().zip(&self.images).fold(GF::zero(), |sum, (c, image)| sum + *image * GF::from(*c))
+    }
+
+    /// Whether `x` lies in the image of the smaller field, which is the subfield `GF(P^M)` of the larger field.
+    pub fn contains(&self, x: &GF<P, N, LARGE, LARGE_MODULUS>) -> bool {
+        x.lies_in_subfield(M)
+    }
+
+    /// The element of the smaller field that embeds as `x`, or `None` if `x` is not in the subfield.
+    pub fn project(&self, x: &GF<P, N, LARGE, LARGE_MODULUS>) -> Option<GF<P, M, SMALL, SMALL_MODULUS>> {
+        if !self.contains(x) {
+            return None;
+        }
+        Some(x.val.iter().zip(&self.projection).fold(GF::zero(), |sum, (c, part)| sum + *part * GF::from(*c)))
+    }
+}
+
+// Default implementation
+impl<const P: u64, const M: usize, const N: usize, const SMALL: Indeterminate, const SMALL_MODULUS: &'static [u64], const LARGE: Indeterminate, const LARGE_MODULUS: &'static [u64]> Default for Embedding<GF<P, M, SMALL, SMALL_MODULUS>, GF<P, N, LARGE, LARGE_MODULUS>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test;
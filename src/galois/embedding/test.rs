@@ -0,0 +1,52 @@
+use super::*;
+use crate::{T, X, Y};
+
+type F4 = GF<2, 2, Y, {&[1, 1, 1]}>;
+type F8 = GF<2, 3, Y>;
+type F64 = GF<2, 6, X>;
+type F9 = GF<3, 2, Y, {&[1, 0, 1]}>;
+type F81 = GF<3, 4, T>;
+
+/// Checks that `embedding` is an injective ring homomorphism that `project` inverts.
+fn check_embedding<const P: u64, const M: usize, const N: usize, const SMALL: Indeterminate, const SMALL_MODULUS: &'static [u64], const LARGE: Indeterminate, const LARGE_MODULUS: &'static [u64]>(embedding: &Embedding<GF<P, M, SMALL, SMALL_MODULUS>, GF<P, N, LARGE, LARGE_MODULUS>>) {
+    assert_eq!(embedding.embed(&GF::one()), GF::one());
+    for a in GF::<P, M, SMALL, SMALL_MODULUS>::elements() {
+        let image = embedding.embed(&a);
+        assert!(embedding.contains(&image));
+        assert_eq!(embedding.project(&image), Some(a));
+        for b in GF::<P, M, SMALL, SMALL_MODULUS>::elements() {
+            assert_eq!(embedding.embed(&(a + b)), image + embedding.embed(&b));
+            assert_eq!(embedding.embed(&(a * b)), image * embedding.embed(&b));
+        }
+    }
+}
+
+#[test]
+fn test_embeddings() {
+    check_embedding(&Embedding::<F4, F64>::new());
+    check_embedding(&Embedding::<F8, F64>::new());
+    check_embedding(&Embedding::<F64, F64>::new());
+    check_embedding(&Embedding::<F9, F81>::new());
+    check_embedding(&Embedding::<GF<3, 1, Y>, F81>::new());
+}
+
+#[test]
+fn test_embedding_root() {
+    let embedding = Embedding::<F9, F81>::new();
+    let root = embedding.root();
+    assert_eq!(root * root + F81::one(), F81::zero());
+    assert_eq!(embedding.embed(&F9::from_index(3)), root);
+    // The other root of Y^2 + 1 is the conjugate, giving the other embedding.
+    let conjugate = Embedding::<F9, F81>::from_root(root.frobenius(1)).unwrap();
+    check_embedding(&conjugate);
+    assert_eq!(conjugate.embed(&F9::from_index(3)), -root);
+    assert!(Embedding::<F9, F81>::from_root(F81::generator()).is_none());
+}
+
+#[test]
+fn test_projection_outside_subfield() {
+    let embedding = Embedding::<F4, F64>::default();
+    assert_eq!(F64::elements().filter(|x| embedding.contains(x)).count(), 4);
+    assert_eq!(F64::elements().filter_map(|x| embedding.project(&x)).count(), 4);
+    assert_eq!(embedding.project(&F64::generator()), None);
+}
@@ -0,0 +1,95 @@
+//! The Frobenius automorphism of `GF(P^N)` and the maps built from it.
+//!
+//! The Galois group of `GF(P^N)` over `GF(P^M)`, for `M` dividing `N`, is generated by `x -> x^(P^M)`. The trace and
+//! norm to `GF(P^M)` are the sum and product of the `N / M` images of an element under that group, and the
+//! characteristic polynomial over `F_P` is the product of `X - x^(P^i)` over all `N` images. Since the images repeat
+//! with period the degree of the element, the minimal polynomial is the product over its distinct conjugates alone.
+
+use crate::{modulo_ints::IMod, polynomial::Polynomial, Indeterminate, Ring};
+
+use super::GF;
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> GF<P, N, SYMBOL, MODULUS> {
+    /// `self^(P^k)`, the `k`-th power of the Frobenius automorphism. Only `k mod N` matters.
+    pub fn frobenius(&self, k: usize) -> Self {
+        (0..k % N).fold(*self, |x, _| x.pow(P as u128))
+    }
+
+    /// The distinct conjugates `self, self^P, self^(P^2), ...`, whose number is the degree of `self` over `F_P`.
+    fn conjugates(&self) -> Vec<Self> {
+        let mut conjugates = vec![*self];
+        let mut next = self.pow(P as u128);
+        while next != *self {
+            conjugates.push(next);
+            next = next.pow(P as u128);
+        }
+        conjugates
+    }
+
+    /// The element of `F_P` that this element equals, which must have no terms in `SYMBOL`.
+    fn to_prime_field(self) -> IMod<P> {
+        debug_assert!(self.val[1..].iter().all(|x| *x == IMod::zero()), "The element does not lie in the prime field");
+        self.val[0]
+    }
+
+    /// The orbit of `self` under `x -> x^(P^m)`, of length `N / m`.
+    fn relative_orbit(&self, m: usize) -> impl Iterator<Item = Self> + '_ {
+        assert!(m > 0 && N.is_multiple_of(m), "GF(P^m) is a subfield of GF(P^N) only when m divides N");
+        (0..N / m).scan(*self, move |x, _| {
+            let current = *x;
+            *x = x.frobenius(m);
+            Some(current)
+        })
+    }
+
+    /// Whether this element lies in the subfield `GF(P^m)`, the elements fixed by `x -> x^(P^m)`. Panics unless `m`
+    /// divides `N`.
+    pub fn lies_in_subfield(&self, m: usize) -> bool {
+        assert!(m > 0 && N.is_multiple_of(m), "GF(P^m) is a subfield of GF(P^N) only when m divides N");
+        self.frobenius(m) == *self
+    }
+
+    /// The trace to `GF(P^m)`, `self + self^(P^m) + ... + self^(P^(N-m))`, as an element of this field. Panics unless
+    /// `m` divides `N`.
+    pub fn relative_trace(&self, m: usize) -> Self {
+        self.relative_orbit(m).fold(Self::zero(), |sum, x| sum + x)
+    }
+
+    /// The norm to `GF(P^m)`, `self * self^(P^m) * ... * self^(P^(N-m))`, as an element of this field. Panics unless
+    /// `m` divides `N`.
+    pub fn relative_norm(&self, m: usize) -> Self {
+        self.relative_orbit(m).fold(Self::one(), |product, x| product * x)
+    }
+
+    /// The absolute trace to `F_P`.
+    pub fn trace(&self) -> IMod<P> {
+        self.relative_trace(1).to_prime_field()
+    }
+
+    /// The absolute norm to `F_P`, which is zero only for zero.
+    pub fn norm(&self) -> IMod<P> {
+        self.relative_norm(1).to_prime_field()
+    }
+
+    /// The monic polynomial over `F_P` of least degree with `self` as a root, in the variable `VAR`. Its roots are
+    /// the distinct conjugates of `self`, so it is irreducible of degree dividing `N`.
+    pub fn minimal_polynomial<const VAR: Indeterminate>(&self) -> Polynomial<IMod<P>, VAR> {
+        Self::product_of_linears(&self.conjugates())
+    }
+
+    /// The characteristic polynomial over `F_P` of multiplication by `self`, in the variable `VAR`. It has degree `N`
+    /// and is the minimal polynomial raised to `N` divided by its degree.
+    pub fn characteristic_polynomial<const VAR: Indeterminate>(&self) -> Polynomial<IMod<P>, VAR> {
+        Self::product_of_linears(&self.relative_orbit(1).collect::<Vec<_>>())
+    }
+
+    /// The product of `VAR - root` over `roots`, which must be closed under the Frobenius map so that every
+    /// coefficient lies in `F_P`.
+    fn product_of_linears<const VAR: Indeterminate>(roots: &[Self]) -> Polynomial<IMod<P>, VAR> {
+        let product = roots.iter().fold(Polynomial::<Self, VAR>::one(), |product, root| &product * &Polynomial::from_coeffs(&[-*root, Self::one()]));
+        Polynomial::from_coeffs(&(0..=roots.len()).map(|i| product.coeff(i).to_prime_field()).collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod test;
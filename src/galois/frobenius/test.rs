@@ -0,0 +1,90 @@
+use super::*;
+use crate::{T, X};
+
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+type F16 = GF<2, 4, T>;
+type F64 = GF<2, 6, T>;
+
+#[test]
+fn test_frobenius() {
+    for x in F16::elements() {
+        assert_eq!(x.frobenius(0), x);
+        assert_eq!(x.frobenius(1), x * x);
+        assert_eq!(x.frobenius(4), x);
+        assert_eq!(x.frobenius(3).frobenius(2), x.frobenius(1));
+    }
+    // The Frobenius map is additive.
+    let (a, b) = (F9::from_index(5), F9::from_index(7));
+    assert_eq!((a + b).frobenius(1), a.frobenius(1) + b.frobenius(1));
+}
+
+#[test]
+fn test_trace_and_norm() {
+    for a in F16::elements() {
+        for b in F16::elements().step_by(5) {
+            assert_eq!((a + b).trace(), a.trace() + b.trace());
+            assert_eq!((a * b).norm(), a.norm() * b.norm());
+        }
+    }
+    assert_eq!(F16::zero().norm(), IMod::zero());
+    assert!(F16::elements().skip(1).all(|x| x.norm() == IMod::one()));
+    // Half the elements of GF(16) have trace one.
+    assert_eq!(F16::elements().filter(|x| x.trace() == IMod::one()).count(), 8);
+    // The norm of a root of the monic modulus is its constant term, up to sign.
+    let root = F9::from_index(3);
+    assert_eq!(root.norm(), IMod::new(2));
+    assert_eq!(root.trace(), -IMod::new(2));
+}
+
+#[test]
+fn test_relative_trace_and_norm() {
+    // GF(64) contains GF(4) and GF(8); the relative maps land in them, which are the fixed fields of x -> x^(2^m).
+    for x in F64::elements().step_by(7) {
+        for m in [1, 2, 3, 6] {
+            assert_eq!(x.relative_trace(m).frobenius(m), x.relative_trace(m));
+            assert_eq!(x.relative_norm(m).frobenius(m), x.relative_norm(m));
+        }
+        assert_eq!(x.relative_trace(6), x);
+        assert_eq!(x.relative_norm(6), x);
+        // Transitivity through GF(8) and GF(4): the absolute maps apply the Frobenius of the subfield afterwards.
+        let through_8 = x.relative_trace(3);
+        assert_eq!((0..3).fold(F64::zero(), |sum, i| sum + through_8.frobenius(i)), x.relative_trace(1));
+        let through_4 = x.relative_norm(2);
+        assert_eq!((0..2).fold(F64::one(), |product, i| product * through_4.frobenius(i)), x.relative_norm(1));
+    }
+}
+
+#[test]
+fn test_lies_in_subfield() {
+    // GF(64) has 2, 4, 8 and 64 elements in its subfields of degree 1, 2, 3 and 6.
+    for (m, count) in [(1, 2), (2, 4), (3, 8), (6, 64)] {
+        assert_eq!(F64::elements().filter(|x| x.lies_in_subfield(m)).count(), count);
+    }
+    assert!(F64::generator().relative_norm(3).lies_in_subfield(3));
+}
+
+#[test]
+#[should_panic(expected = "only when m divides N")]
+fn test_relative_trace_to_non_subfield() {
+    F64::generator().relative_trace(4);
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    for x in F64::elements() {
+        let minimal: Polynomial<IMod<2>, X> = x.minimal_polynomial();
+        let characteristic: Polynomial<IMod<2>, X> = x.characteristic_polynomial();
+        let degree = minimal.deg().unwrap();
+        assert!(minimal.is_irreducible());
+        assert!(6usize.is_multiple_of(degree));
+        assert_eq!(minimal.eval_in(&x), F64::zero());
+        assert_eq!(characteristic.deg(), Some(6));
+        assert_eq!(characteristic, (1..6 / degree).fold(minimal.clone(), |product, _| &product * &minimal));
+    }
+    // The generator of a field on a Conway polynomial has that polynomial as its minimal polynomial.
+    assert_eq!(F16::generator().minimal_polynomial::<T>(), F16::modulus());
+    // Elements of the prime field.
+    let two = F9::from_index(2);
+    assert_eq!(two.minimal_polynomial::<X>(), Polynomial::from_coeffs(&[IMod::new(1), IMod::one()]));
+    assert_eq!(two.characteristic_polynomial::<X>(), Polynomial::from_coeffs(&[IMod::new(1), IMod::new(2), IMod::one()]));
+}
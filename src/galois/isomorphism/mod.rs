@@ -0,0 +1,69 @@
+//! Isomorphisms between copies of `GF(P^N)` built on different moduli.
+//!
+//! Any two fields with `P^N` elements are isomorphic, but the map depends on the moduli. An isomorphism is the
+//! embedding of one field into the other that sends its symbol to a root of its modulus, as found by `Embedding`.
+//! Its inverse sends the symbol of the second field back to that element's preimage, which the projection of the
+//! first embedding provides.
+
+use crate::{polynomial::Polynomial, Indeterminate};
+
+use super::{embedding::Embedding, GF};
+
+/// An isomorphism from the field `A` to the field `B`, with its inverse.
+#[derive(Clone, Debug)]
+pub struct Isomorphism<A, B> {
+    forward: Embedding<A, B>,
+    backward: Embedding<B, A>,
+}
+
+impl<const P: u64, const N: usize, const A: Indeterminate, const A_MODULUS: &'static [u64], const B: Indeterminate, const B_MODULUS: &'static [u64]> Isomorphism<GF<P, N, A, A_MODULUS>, GF<P, N, B, B_MODULUS>> {
+    /// The isomorphism sending the symbol of the first field to the first root of its modulus in the second field,
+    /// in the order of `IndexedField::index`.
+    pub fn new() -> Self {
+        Self::from_embedding(Embedding::new())
+    }
+
+    /// The isomorphism sending the symbol of the first field to `root`, or `None` if `root` is not a root of the
+    /// first field's modulus.
+    pub fn from_root(root: GF<P, N, B, B_MODULUS>) -> Option<Self> {
+        Embedding::from_root(root).map(Self::from_embedding)
+    }
+
+    fn from_embedding(forward: Embedding<GF<P, N, A, A_MODULUS>, GF<P, N, B, B_MODULUS>>) -> Self {
+        let symbol = GF::<P, N, B, B_MODULUS>::from_poly(&Polynomial::indeterminant_power(1));
+        let preimage = forward.project(&symbol).expect("An embedding between fields of the same size is onto");
+        let backward = Embedding::from_root(preimage).expect("The preimage of a root of a modulus is a root of it");
+        Self {
+            forward,
+            backward,
+        }
+    }
+
+    /// The image of `x` in the second field.
+    pub fn forward(&self, x: &GF<P, N, A, A_MODULUS>) -> GF<P, N, B, B_MODULUS> {
+        self.forward.embed(x)
+    }
+
+    /// The preimage of `y` in the first field.
+    pub fn backward(&self, y: &GF<P, N, B, B_MODULUS>) -> GF<P, N, A, A_MODULUS> {
+        self.backward.embed(y)
+    }
+
+    /// The inverse isomorphism, from the second field to the first.
+    pub fn inverse(&self) -> Isomorphism<GF<P, N, B, B_MODULUS>, GF<P, N, A, A_MODULUS>> {
+        Isomorphism {
+            forward: self.backward.clone(),
+            backward: self.forward.clone(),
+        }
+    }
+}
+
+// Default implementation
+impl<const P: u64, const N: usize, const A: Indeterminate, const A_MODULUS: &'static [u64], const B: Indeterminate, const B_MODULUS: &'static [u64]> Default for Isomorphism<GF<P, N, A, A_MODULUS>, GF<P, N, B, B_MODULUS>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test;
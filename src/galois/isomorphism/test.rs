@@ -0,0 +1,50 @@
+use super::*;
+use crate::{Ring, T, X};
+
+// GF(9) on X^2 + 1 and on the Conway polynomial X^2 + 2X + 2.
+type F9 = GF<3, 2, X, {&[1, 0, 1]}>;
+type F9Conway = GF<3, 2, T>;
+// GF(256) on the AES polynomial and on the Conway polynomial.
+type Aes = GF<2, 8, X, {&[1, 1, 0, 1, 1, 0, 0, 0, 1]}>;
+type F256 = GF<2, 8, T>;
+
+/// Checks that `iso` is a ring isomorphism whose two directions are inverse.
+fn check_isomorphism<const P: u64, const N: usize, const A: Indeterminate, const A_MODULUS: &'static [u64], const B: Indeterminate, const B_MODULUS: &'static [u64]>(iso: &Isomorphism<GF<P, N, A, A_MODULUS>, GF<P, N, B, B_MODULUS>>, step: usize) {
+    assert_eq!(iso.forward(&GF::one()), GF::one());
+    for a in GF::<P, N, A, A_MODULUS>::elements().step_by(step) {
+        assert_eq!(iso.backward(&iso.forward(&a)), a);
+        for b in GF::<P, N, A, A_MODULUS>::elements().step_by(step) {
+            assert_eq!(iso.forward(&(a + b)), iso.forward(&a) + iso.forward(&b));
+            assert_eq!(iso.forward(&(a * b)), iso.forward(&a) * iso.forward(&b));
+        }
+    }
+    for y in GF::<P, N, B, B_MODULUS>::elements().step_by(step) {
+        assert_eq!(iso.forward(&iso.backward(&y)), y);
+    }
+}
+
+#[test]
+fn test_isomorphism() {
+    check_isomorphism(&Isomorphism::<F9, F9Conway>::new(), 1);
+    check_isomorphism(&Isomorphism::<Aes, F256>::new(), 7);
+    check_isomorphism(&Isomorphism::<F256, Aes>::default(), 7);
+    check_isomorphism(&Isomorphism::<GF<5, 1, X>, GF<5, 1, T, {&[2, 1]}>>::new(), 1);
+}
+
+#[test]
+fn test_isomorphism_roots_and_inverse() {
+    let iso = Isomorphism::<F9, F9Conway>::new();
+    // The symbol X of F9 squares to -1, and so must its image.
+    let image = iso.forward(&F9::from_index(3));
+    assert_eq!(image * image, -F9Conway::one());
+    let inverse = iso.inverse();
+    check_isomorphism(&inverse, 1);
+    assert_eq!(inverse.forward(&image), F9::from_index(3));
+    // The other root of X^2 + 1 gives the composition with the Frobenius map.
+    let conjugate = Isomorphism::<F9, F9Conway>::from_root(-image).unwrap();
+    check_isomorphism(&conjugate, 1);
+    for x in F9::elements() {
+        assert_eq!(conjugate.forward(&x), iso.forward(&x).frobenius(1));
+    }
+    assert!(Isomorphism::<F9, F9Conway>::from_root(F9Conway::one()).is_none());
+}
@@ -1,13 +1,639 @@
-use crate::{modulo_ints::IMod, polynomial::Polynomial, Field, Indeterminate};
+use std::{any::TypeId, cell::RefCell, collections::HashMap, fmt::{self, Display}, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}, str::FromStr, sync::{Mutex, OnceLock}};
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct GF<const P: u64, const N: usize, const SYMBOL: Indeterminate> {
-    val: [IMod<P>; N],
-    irred_poly: Polynomial<IMod<P>,SYMBOL>
+pub mod binary;
+pub mod conway;
+pub mod embedding;
+pub mod isomorphism;
+mod frobenius;
+pub mod zech;
+
+use crate::{modulo_ints::IMod, polynomial::{dense::DensePolynomial, parse::ParsePolynomialError, Polynomial}, Error, FiniteField, Field, IndexedField, Indeterminate, Latex, Ring};
+
+/// Passing `CONWAY` as the `MODULUS` of a `GF` (which is the default) builds the field on its Conway polynomial.
+pub const CONWAY: &[u64] = &[];
+
+/// `a * SYMBOL` modulo the monic modulus of degree `N` with `SYMBOL^N = reduction`, on coefficients below `p`.
+const fn times_symbol<const N: usize>(a: [u128; N], reduction: &[u128; N], p: u128) -> [u128; N] {
+    let overflow = a[N - 1];
+    let mut result = [0; N];
+    let mut i = N - 1;
+    while i > 0 {
+        result[i] = (a[i - 1] + overflow * reduction[i] % p) % p;
+        i -= 1;
+    }
+    result[0] = overflow * reduction[0] % p;
+    result
+}
+
+/// `a * b` modulo the modulus, by Horner's rule over the coefficients of `b`.
+const fn mul_mod<const N: usize>(a: &[u128; N], b: &[u128; N], reduction: &[u128; N], p: u128) -> [u128; N] {
+    let mut result = [0; N];
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        result = times_symbol(result, reduction, p);
+        let mut j = 0;
+        while j < N {
+            result[j] = (result[j] + b[i] * a[j] % p) % p;
+            j += 1;
+        }
+    }
+    result
+}
+
+/// `a^exp` modulo the modulus, by repeated squaring.
+const fn pow_mod<const N: usize>(a: &[u128; N], mut exp: u128, reduction: &[u128; N], p: u128) -> [u128; N] {
+    let mut result = [0; N];
+    result[0] = 1;
+    let mut base = *a;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(&result, &base, reduction, p);
+        }
+        base = mul_mod(&base, &base, reduction, p);
+        exp /= 2;
+    }
+    result
+}
+
+/// Whether `g` is coprime to the modulus, i.e. multiplication by `g` is invertible, by row reducing its matrix.
+const fn is_unit<const N: usize>(g: &[u128; N], reduction: &[u128; N], p: u128) -> bool {
+    let mut rows = [[0; N]; N];
+    let mut row = *g;
+    let mut i = 0;
+    while i < N {
+        rows[i] = row;
+        row = times_symbol(row, reduction, p);
+        i += 1;
+    }
+    let mut col = 0;
+    while col < N {
+        let mut pivot = col;
+        while pivot < N && rows[pivot][col] == 0 {
+            pivot += 1;
+        }
+        if pivot == N {
+            return false;
+        }
+        let swapped = rows[pivot];
+        rows[pivot] = rows[col];
+        rows[col] = swapped;
+        let inverse = match crate::tools::mod_inverse(rows[col][col] as u64, p as u64) {
+            Some(x) => x as u128,
+            None => return false,
+        };
+        let mut r = col + 1;
+        while r < N {
+            let factor = rows[r][col] * inverse % p;
+            let mut c = col;
+            while c < N {
+                rows[r][c] = (rows[r][c] + (p - factor) * rows[col][c] % p) % p;
+                c += 1;
+            }
+            r += 1;
+        }
+        col += 1;
+    }
+    true
+}
+
+/// The largest estimated cost, `2 N^3 log P`, of a compile-time irreducibility test. This admits up to `GF(2^50)`,
+/// about half the cost at which rustc's `long_running_const_eval` lint fires (between `GF(2^64)` and `GF(2^72)`).
+const CONST_IRREDUCIBILITY_BUDGET: u128 = 1 << 19;
+
+/// The result of `test` for the field `F`, run on first use and shared for the rest of the program. Each thread keeps
+/// its own copy of the results it has seen, so that only its first lookup for a field takes the lock.
+fn cached_irreducibility<F: 'static>(test: impl FnOnce() -> bool) -> bool {
+    thread_local! {
+        static SEEN: RefCell<HashMap<TypeId, bool>> = RefCell::default();
+    }
+    static REGISTRY: OnceLock<Mutex<HashMap<TypeId, bool>>> = OnceLock::new();
+    let id = TypeId::of::<F>();
+    if let Some(irreducible) = SEEN.with_borrow(|seen| seen.get(&id).copied()) {
+        return irreducible;
+    }
+    let irreducible = *REGISTRY.get_or_init(Default::default).lock().expect("Irreducibility registry poisoned").entry(id).or_insert_with(test);
+    SEEN.with_borrow_mut(|seen| seen.insert(id, irreducible));
+    irreducible
+}
+
+/// Rabin's test on the monic modulus of degree `N` with `SYMBOL^N = reduction` over `F_p`: it divides
+/// `SYMBOL^(p^N) - SYMBOL` and is coprime to `SYMBOL^(p^(N/q)) - SYMBOL` for every prime `q` dividing `N`.
+const fn is_irreducible<const N: usize>(reduction: &[u128; N], p: u128) -> bool {
+    if N <= 1 {
+        return N == 1;
+    }
+    let mut symbol = [0; N];
+    symbol[1] = 1;
+    // `power` is `SYMBOL^(p^k)` at the end of step `k`.
+    let mut power = symbol;
+    let mut k = 1;
+    while k <= N {
+        power = pow_mod(&power, p, reduction, p);
+        if k < N && N.is_multiple_of(k) && crate::tools::is_prime((N / k) as u128) {
+            let mut difference = power;
+            difference[1] = (difference[1] + p - 1) % p;
+            if !is_unit(&difference, reduction, p) {
+                return false;
+            }
+        }
+        k += 1;
+    }
+    let mut i = 0;
+    while i < N {
+        if power[i] != symbol[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// An element of the Galois field `GF(P^N)`, represented as a polynomial in `SYMBOL` of degree less than `N`
+/// over `IMod<P>`, reduced modulo the irreducible polynomial `MODULUS` of degree `N`.
+///
+/// `MODULUS[i]` is the coefficient of `SYMBOL^i`, so `MODULUS` has exactly `N + 1` entries. Since the modulus is
+/// part of the type, elements of fields with different moduli cannot be mixed, and elements are plain coefficient
+/// arrays. For example, `GF<2, 2, X, {&[1, 1, 1]}>` is `GF(4)` built as `F2[X]/(X^2 + X + 1)`. A `MODULUS` that is not
+/// irreducible fails to compile as soon as an element is constructed. Fields too large to test at compile time, such
+/// as `GF(2^128)`, test their modulus once at runtime instead, and constructing an element of one with a reducible
+/// modulus panics.
+///
+/// When `MODULUS` is omitted (or is `CONWAY`) the Conway polynomial for `GF(P^N)` from `conway::CONWAY_POLYNOMIALS`
+/// is used, so that `GF<P, N, SYMBOL>` matches the representation chosen by SageMath, Magma and GAP. The table only
+/// covers small fields, and `GF<P, N, SYMBOL>` does not compile for any other pair: those fields need a hand-written
+/// `MODULUS`. `conway::conway_polynomial` can find the Conway polynomial for such a pair, but only at runtime, so its
+/// coefficients have to be copied into the `MODULUS` slice by hand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GF<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64] = CONWAY> {
+    val: [IMod<P>; N]
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> GF<P, N, SYMBOL, MODULUS> {
+    /// The coefficients of the modulus actually in use, resolving `CONWAY` through the table.
+    const COEFFS: &'static [u64] = if MODULUS.is_empty() {
+        match conway::lookup(P, N) {
+            Some(coeffs) => coeffs,
+            None => panic!("No Conway polynomial is tabulated for GF(P^N), so a hand-written MODULUS must be given"),
+        }
+    } else {
+        MODULUS
+    };
+
+    /// `SYMBOL^N` written in terms of lower powers, i.e. the negated non-leading coefficients of the monic modulus.
+    const REDUCTION: [IMod<P>; N] = {
+        let coeffs = Self::COEFFS;
+        assert!(coeffs.len() == N + 1 && !coeffs[N].is_multiple_of(P), "The modulus of GF(P^N) must have degree N");
+        let leading_inverse = match crate::tools::mod_inverse(coeffs[N] % P, P) {
+            Some(x) => x as u128,
+            None => panic!("The leading coefficient of the modulus must be a unit"),
+        };
+        let mut reduction = [IMod::new(0); N];
+        let mut i = 0;
+        while i < N {
+            let monic = IMod::<P>::new((coeffs[i] as u128) * leading_inverse).value();
+            reduction[i] = IMod::new(P as u128 - monic);
+            i += 1;
+        }
+        reduction
+    };
+
+    /// `REDUCTION` as raw residues, for the const irreducibility test.
+    const REDUCTION_VALUES: [u128; N] = {
+        let mut reduction = [0; N];
+        let mut i = 0;
+        while i < N {
+            reduction[i] = Self::REDUCTION[i].value();
+            i += 1;
+        }
+        reduction
+    };
+
+    /// Whether `MODULUS` is irreducible, decided once per field at compile time by Rabin's test, or `None` if the
+    /// field is too large for that. The test takes about `2 N^3 log P` steps, and beyond `CONST_IRREDUCIBILITY_BUDGET`
+    /// const evaluation would run into rustc's `long_running_const_eval` lint; such fields are tested at runtime.
+    const IRREDUCIBLE: Option<bool> = {
+        // Each of the N steps raises to the power P with about 2 log P products of N^2 operations each.
+        let cost = 2 * (N as u128).pow(3) * (P.ilog2() as u128 + 1);
+        if cost > CONST_IRREDUCIBILITY_BUDGET {
+            None
+        } else {
+            Some(is_irreducible(&Self::REDUCTION_VALUES, P as u128))
+        }
+    };
+
+    /// Referenced by every constructor, so that a `MODULUS` of the wrong degree, or a reducible one in a field small
+    /// enough to test at compile time, fails to compile.
+    const VALID: () = assert!(!matches!(Self::IRREDUCIBLE, Some(false)), "The modulus of GF(P^N) must be irreducible");
+
+    /// Whether `MODULUS` is irreducible: the compile-time answer where there is one, and otherwise Rabin's test run
+    /// on first use and cached for the rest of the program.
+    fn modulus_is_irreducible() -> bool {
+        match Self::IRREDUCIBLE {
+            Some(irreducible) => irreducible,
+            None => cached_irreducibility::<Self>(|| is_irreducible(&Self::REDUCTION_VALUES, P as u128)),
+        }
+    }
+
+    /// Called by every constructor. Rejects a reducible `MODULUS` at compile time where `IRREDUCIBLE` is known, and
+    /// by a panic otherwise.
+    fn check_modulus() {
+        let () = Self::VALID;
+        if Self::IRREDUCIBLE.is_none() {
+            assert!(Self::modulus_is_irreducible(), "The modulus of GF(P^N) must be irreducible");
+        }
+    }
+
+    /// `val[i]` is the coefficient of `SYMBOL^i`.
+    pub fn new(val: &[IMod<P>;N]) -> Self {
+        Self::check_modulus();
+        Self {
+            val: *val
+        }
+    }
+
+    /// `val[i]` is the coefficient of `SYMBOL^i`, taking a slice of any length.
+    ///
+    /// Fails with `Error::DegreeMismatch` unless `val` has exactly `N` entries, and with `Error::ReducibleModulus`
+    /// if `MODULUS` is not irreducible. Where `new` rejects a reducible `MODULUS`, this reads the same once-per-type
+    /// check as a value, so it reports the error at runtime without repeating the test.
+    pub fn try_new(val: &[IMod<P>]) -> Result<Self, Error> {
+        let val: [IMod<P>; N] = val.try_into().map_err(|_| Error::DegreeMismatch { expected: N, found: val.len() })?;
+        if !Self::modulus_is_irreducible() {
+            return Err(Error::ReducibleModulus);
+        }
+        Ok(Self { val })
+    }
+
+    /// The defining polynomial of this field, as a `Polynomial` in `SYMBOL`.
+    pub fn modulus() -> Polynomial<IMod<P>,SYMBOL> {
+        Polynomial::from_coeffs(&Self::COEFFS.iter().map(|&x| IMod::new(x as u128)).collect::<Vec<_>>())
+    }
+
+    /// The element represented by `poly`, reduced modulo `MODULUS`.
+    pub fn from_poly(poly: &Polynomial<IMod<P>,SYMBOL>) -> Self {
+        Self::from_dense(&DensePolynomial::from(poly))
+    }
+
+    /// The modulus in dense form, for reduction without hashing.
+    fn dense_modulus() -> DensePolynomial<IMod<P>,SYMBOL> {
+        DensePolynomial::new(Self::COEFFS.iter().map(|&x| IMod::new(x as u128)).collect())
+    }
+
+    /// The element represented by `poly`, reduced modulo `MODULUS`.
+    fn from_dense(poly: &DensePolynomial<IMod<P>,SYMBOL>) -> Self {
+        Self::check_modulus();
+        let reduced = poly % &Self::dense_modulus();
+        Self {
+            val: std::array::from_fn(|i| reduced.coeff(i))
+        }
+    }
+
+    /// This element as a polynomial in `SYMBOL` of degree less than `N`.
+    pub fn to_poly(&self) -> Polynomial<IMod<P>,SYMBOL> {
+        Polynomial::from_coeffs(&self.val)
+    }
+
+    /// The coefficients of this element as a polynomial in `SYMBOL`.
+    pub fn coefficients(&self) -> &[IMod<P>; N] {
+        &self.val
+    }
+
+    /// The number of elements of the field, `P^N`. The same as `FiniteField::size`, available without importing the
+    /// trait.
+    pub fn size() -> u128 {
+        <Self as FiniteField>::size()
+    }
+
+    /// `self^exp`, by repeated squaring, as `FiniteField::pow`.
+    pub fn pow(&self, exp: u128) -> Self {
+        <Self as FiniteField>::pow(self, exp)
+    }
+
+    /// The order `P^N - 1` of the multiplicative group, as `FiniteField::group_order`. Unlike `size()`, this fits in
+    /// a `u128` for `GF(2^128)`.
+    pub fn group_order() -> u128 {
+        <Self as FiniteField>::group_order()
+    }
+
+    /// The order of this element in the multiplicative group, or `None` for zero, as
+    /// `FiniteField::multiplicative_order`.
+    pub fn multiplicative_order(&self) -> Option<u128> {
+        <Self as FiniteField>::multiplicative_order(self)
+    }
+
+    /// Whether this element generates the multiplicative group of the field, as `FiniteField::is_primitive`.
+    pub fn is_primitive(&self) -> bool {
+        <Self as FiniteField>::is_primitive(self)
+    }
+
+    /// Every element of the field, in the order given by `index`.
+    pub fn elements() -> impl Iterator<Item = Self> {
+        (0..Self::size()).map(Self::from_index)
+    }
+
+    /// The position of this element in `elements`: its coefficients read as the digits of a base-`P` number, with
+    /// the coefficient of `SYMBOL^0` least significant.
+    pub fn index(&self) -> u128 {
+        self.val.iter().rev().fold(0, |index, digit| index * P as u128 + digit.value())
+    }
+
+    /// The element at position `index` in `elements`.
+    pub fn from_index(mut index: u128) -> Self {
+        Self::check_modulus();
+        Self {
+            val: std::array::from_fn(|_| {
+                let digit = IMod::new(index % P as u128);
+                index /= P as u128;
+                digit
+            })
+        }
+    }
+
+    /// The first primitive element in the order of `elements`. For `N > 1` with a primitive `MODULUS` (as Conway
+    /// polynomials are) this is `SYMBOL` itself.
+    pub fn generator() -> Self {
+        Self::elements().find(Self::is_primitive).expect("The multiplicative group of a finite field is cyclic")
+    }
+
+    /// Multiplies by `SYMBOL` in place, folding the overflowing `SYMBOL^N` term back in via `REDUCTION`.
+    fn mul_by_symbol(&mut self) {
+        let overflow = self.val[N-1];
+        for i in (1..N).rev() {
+            self.val[i] = self.val[i-1] + overflow * Self::REDUCTION[i];
+        }
+        self.val[0] = overflow * Self::REDUCTION[0];
+    }
+
+    /// Multiplicative inverse as the inverse of `val` modulo `MODULUS`, from the extended GCD.
+    pub fn inverse(&self) -> Option<Self> {
+        DensePolynomial::from_coeffs(&self.val).inverse_mod(&Self::dense_modulus()).map(|inverse| Self::from_dense(&inverse))
+    }
+
+    /// The inverse, or `Error::DivisionByZero` for zero. As this is a field, it never fails with `Error::NonUnit`.
+    pub fn try_inverse(&self) -> Result<Self, Error> {
+        self.inverse().ok_or(Error::DivisionByZero)
+    }
+
+    /// `self / rhs`, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, Error> {
+        rhs.try_inverse().map(|inverse| *self * inverse)
+    }
+
+    /// The remainder of `self` by `rhs`, which is always zero in a field, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_rem(&self, rhs: &Self) -> Result<Self, Error> {
+        self.checked_div(rhs).map(|_| Self::zero())
+    }
+
+    /// The unique `P`-th root of this element. Since `x^(P^N) = x`, the Frobenius map `x -> x^P` is inverted by
+    /// `x -> x^(P^(N-1))`, taken as `N - 1` successive `P`-th powers so that no exponent overflows.
+    pub fn pth_root(&self) -> Self {
+        self.frobenius(N - 1)
+    }
+}
+
+// Add implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Add for GF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        GF {
+            val: std::array::from_fn(|i| self.val[i] + rhs.val[i])
+        }
+    }
+}
+
+// Sub implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Sub for GF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        GF {
+            val: std::array::from_fn(|i| self.val[i] - rhs.val[i])
+        }
+    }
+}
+
+// Mul implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Mul for GF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Horner's rule over the coefficients of `self`, so no intermediate product of degree >= N is ever formed.
+        let mut out = GF::zero();
+        for i in (0..N).rev() {
+            out.mul_by_symbol();
+            for j in 0..N {
+                out.val[j] += self.val[i] * rhs.val[j];
+            }
+        }
+        out
+    }
+}
+
+// Div implementation
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Div for GF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Attempted to divide by a non-unit")
+    }
 }
 
-impl<const P: u64, const N: usize, const SYMBOL: Indeterminate> GF<P, N, SYMBOL> {
-    pub fn new(val: &[IMod<P>;N], irred_poly: &[IMod<P>]) -> Self {
-        todo!()
+// Neg implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Neg for GF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        GF {
+            val: self.val.map(|x| -x)
+        }
     }
-}
\ No newline at end of file
+}
+
+// AddAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> AddAssign for GF<P, N, SYMBOL, MODULUS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> AddAssign<&GF<P, N, SYMBOL, MODULUS>> for GF<P, N, SYMBOL, MODULUS> {
+    fn add_assign(&mut self, rhs: &GF<P, N, SYMBOL, MODULUS>) {
+        *self = *self + *rhs;
+    }
+}
+
+// SubAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> SubAssign for GF<P, N, SYMBOL, MODULUS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> SubAssign<&GF<P, N, SYMBOL, MODULUS>> for GF<P, N, SYMBOL, MODULUS> {
+    fn sub_assign(&mut self, rhs: &GF<P, N, SYMBOL, MODULUS>) {
+        *self = *self - *rhs;
+    }
+}
+
+// MulAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> MulAssign for GF<P, N, SYMBOL, MODULUS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> MulAssign<&GF<P, N, SYMBOL, MODULUS>> for GF<P, N, SYMBOL, MODULUS> {
+    fn mul_assign(&mut self, rhs: &GF<P, N, SYMBOL, MODULUS>) {
+        *self = *self * *rhs;
+    }
+}
+
+// DivAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> DivAssign for GF<P, N, SYMBOL, MODULUS> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> DivAssign<&GF<P, N, SYMBOL, MODULUS>> for GF<P, N, SYMBOL, MODULUS> {
+    fn div_assign(&mut self, rhs: &GF<P, N, SYMBOL, MODULUS>) {
+        *self = *self / *rhs;
+    }
+}
+
+// Reference operations for Ring trait bounds
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Add<&'b GF<P, N, SYMBOL, MODULUS>> for &GF<P, N, SYMBOL, MODULUS> {
+    type Output = GF<P, N, SYMBOL, MODULUS>;
+
+    fn add(self, rhs: &'b GF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Mul<&'b GF<P, N, SYMBOL, MODULUS>> for &GF<P, N, SYMBOL, MODULUS> {
+    type Output = GF<P, N, SYMBOL, MODULUS>;
+
+    fn mul(self, rhs: &'b GF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Sub<&'b GF<P, N, SYMBOL, MODULUS>> for &GF<P, N, SYMBOL, MODULUS> {
+    type Output = GF<P, N, SYMBOL, MODULUS>;
+
+    fn sub(self, rhs: &'b GF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Neg for &GF<P, N, SYMBOL, MODULUS> {
+    type Output = GF<P, N, SYMBOL, MODULUS>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Div<&'b GF<P, N, SYMBOL, MODULUS>> for &GF<P, N, SYMBOL, MODULUS> {
+    type Output = GF<P, N, SYMBOL, MODULUS>;
+
+    fn div(self, rhs: &'b GF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Ring for GF<P, N, SYMBOL, MODULUS> {
+    fn zero() -> Self {
+        Self::check_modulus();
+        Self {
+            val: [IMod::zero(); N]
+        }
+    }
+
+    fn one() -> Self {
+        Self::check_modulus();
+        let mut val = [IMod::zero(); N];
+        val[0] = IMod::one();
+        Self {
+            val
+        }
+    }
+
+    fn multiply_coefficients(a: &[Self], b: &[Self]) -> Vec<Self> {
+        crate::polynomial::multiplication::toom3(a, b)
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Field for GF<P, N, SYMBOL, MODULUS> {
+
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> FiniteField for GF<P, N, SYMBOL, MODULUS> {
+    fn size() -> u128 {
+        (P as u128).checked_pow(N as u32).expect("P^N must fit in a u128")
+    }
+
+    /// `P^N - 1`, computed as `(P - 1)(1 + P + ... + P^(N-1))` so that `P^N` itself is never formed. This fits in a
+    /// `u128` for `GF(2^128)`, whose `size()` does not.
+    fn group_order() -> u128 {
+        let p = P as u128;
+        let mut sum: u128 = 0;
+        let mut power: u128 = 1;
+        for i in 0..N {
+            sum = sum.checked_add(power).expect("P^N - 1 must fit in a u128");
+            if i + 1 < N {
+                power = power.checked_mul(p).expect("P^N - 1 must fit in a u128");
+            }
+        }
+        (p - 1).checked_mul(sum).expect("P^N - 1 must fit in a u128")
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> IndexedField for GF<P, N, SYMBOL, MODULUS> {
+    fn from_index(index: u128) -> Self {
+        GF::from_index(index)
+    }
+
+    fn index(&self) -> u128 {
+        GF::index(self)
+    }
+}
+
+// From implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> From<IMod<P>> for GF<P, N, SYMBOL, MODULUS> {
+    /// The constant `x`, embedding the prime field.
+    fn from(x: IMod<P>) -> Self {
+        GF::from_index(x.value())
+    }
+}
+
+// Display implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Display for GF<P, N, SYMBOL, MODULUS> {
+    /// The representing polynomial in `SYMBOL`, such as `T^2 + 2`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_poly())
+    }
+}
+
+// Latex implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Latex for GF<P, N, SYMBOL, MODULUS> {
+    fn to_latex(&self) -> String {
+        self.to_poly().to_latex()
+    }
+}
+
+// FromStr implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> FromStr for GF<P, N, SYMBOL, MODULUS> {
+    type Err = ParsePolynomialError;
+
+    /// A polynomial in `SYMBOL`, such as `T^2 + 2`, reduced modulo `MODULUS`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(|poly| Self::from_poly(&poly))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,285 @@
+use super::*;
+use crate::X;
+
+type F2 = IMod<2>;
+type F3 = IMod<3>;
+
+// GF(4) = F2[X]/(X^2 + X + 1)
+type GF4 = GF<2, 2, X, {&[1, 1, 1]}>;
+// GF(8) = F2[X]/(X^3 + X + 1)
+type GF8 = GF<2, 3, X, {&[1, 1, 0, 1]}>;
+// GF(9) = F3[X]/(X^2 + 1)
+type GF9 = GF<3, 2, X, {&[1, 0, 1]}>;
+
+fn gf4(a: u128, b: u128) -> GF4 {
+    GF::new(&[F2::new(a), F2::new(b)])
+}
+
+fn gf9(a: u128, b: u128) -> GF9 {
+    GF::new(&[F3::new(a), F3::new(b)])
+}
+
+#[test]
+fn test_gf_addition() {
+    // (1 + X) + X = 1 in characteristic 2
+    assert_eq!(gf4(1, 1) + gf4(0, 1), gf4(1, 0));
+    // (2 + X) + (2 + 2X) = 1 in GF(9)
+    assert_eq!(gf9(2, 1) + gf9(2, 2), gf9(1, 0));
+}
+
+#[test]
+fn test_gf_multiplication() {
+    // X * X = X + 1 in GF(4)
+    assert_eq!(gf4(0, 1) * gf4(0, 1), gf4(1, 1));
+    // X * X = -1 = 2 in GF(9)
+    assert_eq!(gf9(0, 1) * gf9(0, 1), gf9(2, 0));
+    // (1 + X)(1 + 2X) = 1 + 3X + 2X^2 = 1 - 2 = 2 in GF(9)
+    assert_eq!(gf9(1, 1) * gf9(1, 2), gf9(2, 0));
+}
+
+#[test]
+fn test_gf_multiplication_matches_polynomial_reduction() {
+    // The in-place Horner multiplication must agree with multiplying and reducing as polynomials.
+    let elements: Vec<GF8> = (0..8u128)
+        .map(|i| GF::new(&[F2::new(i & 1), F2::new((i >> 1) & 1), F2::new((i >> 2) & 1)]))
+        .collect();
+    for a in &elements {
+        for b in &elements {
+            assert_eq!(a * b, GF8::from_poly(&(a.to_poly() * b.to_poly())));
+        }
+    }
+}
+
+#[test]
+fn test_gf_from_poly_reduces() {
+    // X^3 reduces to X + 1 in GF(8).
+    let x_cubed: Polynomial<F2, X> = Polynomial::indeterminant_power(3);
+    assert_eq!(GF8::from_poly(&x_cubed), GF::new(&[F2::new(1), F2::new(1), F2::new(0)]));
+    assert_eq!(GF8::modulus().deg(), Some(3));
+}
+
+#[test]
+fn test_gf_inverse() {
+    // Every non-zero element of GF(9) has an inverse.
+    for a in 0..3 {
+        for b in 0..3 {
+            let x = gf9(a, b);
+            if x == GF::zero() {
+                assert!(x.inverse().is_none());
+            } else {
+                let inv = x.inverse().unwrap();
+                assert_eq!(x * inv, GF::one());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gf_checked() {
+    assert_eq!(GF9::try_new(&[F3::new(2), F3::new(1)]), Ok(gf9(2, 1)));
+    assert_eq!(GF9::try_new(&[F3::new(2)]), Err(Error::DegreeMismatch { expected: 2, found: 1 }));
+    assert_eq!(GF9::try_new(&[F3::one(); 3]), Err(Error::DegreeMismatch { expected: 2, found: 3 }));
+    // X^2 + 1 = (X + 1)^2 over F2, so it does not define GF(4).
+    assert_eq!(GF::<2, 2, X, {&[1, 0, 1]}>::try_new(&[F2::one(), F2::one()]), Err(Error::ReducibleModulus));
+    for x in GF9::elements().skip(1) {
+        assert_eq!(x.try_inverse(), Ok(x.inverse().unwrap()));
+        assert_eq!(GF9::one().checked_div(&x), x.try_inverse());
+    }
+    assert_eq!(GF9::zero().try_inverse(), Err(Error::DivisionByZero));
+    assert_eq!(gf9(1, 2).checked_div(&GF9::zero()), Err(Error::DivisionByZero));
+    assert_eq!(gf9(1, 2).checked_rem(&gf9(0, 1)), Ok(GF9::zero()));
+    assert_eq!(gf9(1, 2).checked_rem(&GF9::zero()), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn test_gf_pth_root() {
+    for x in GF9::elements() {
+        assert_eq!(x.pth_root().pow(3), x);
+    }
+    for x in GF8::elements() {
+        assert_eq!(x.pth_root() * x.pth_root(), x);
+    }
+}
+
+#[test]
+fn test_gf_division() {
+    let a = gf9(2, 1);
+    let b = gf9(1, 1);
+    let q = a / b;
+    assert_eq!(q * b, a);
+}
+
+#[test]
+fn test_gf_zero_and_one() {
+    let a = gf4(1, 1);
+    assert_eq!(a + GF::zero(), a);
+    assert_eq!(a * GF::one(), a);
+    assert_eq!((GF9::one() + GF::one()) * gf9(0, 1), gf9(0, 2));
+}
+
+#[test]
+fn test_gf_assign_operations() {
+    let mut a = gf9(1, 2);
+    a += gf9(1, 1);
+    assert_eq!(a, gf9(2, 0));
+    a -= &gf9(0, 1);
+    assert_eq!(a, gf9(2, 2));
+    a *= gf9(0, 1);
+    // (2 + 2X) X = 2X + 2X^2 = 2X - 2 = 1 + 2X
+    assert_eq!(a, gf9(1, 2));
+    a /= &gf9(1, 2);
+    assert_eq!(a, GF::one());
+}
+
+#[test]
+fn test_gf_non_monic_modulus() {
+    // 2X^2 + 2 defines the same field as X^2 + 1 over F3.
+    type Scaled = GF<3, 2, X, {&[2, 0, 2]}>;
+    let x: Scaled = GF::new(&[F3::new(0), F3::new(1)]);
+    assert_eq!(x * x, GF::new(&[F3::new(2), F3::new(0)]));
+}
+
+#[test]
+#[should_panic(expected = "non-unit")]
+fn test_gf_division_by_zero() {
+    let _result = gf4(1, 0) / gf4(0, 0);
+}
+
+#[test]
+fn test_gf_modulus_irreducibility_is_checked_at_compile_time() {
+    const { assert!(matches!(GF4::IRREDUCIBLE, Some(true))) };
+    const { assert!(matches!(GF8::IRREDUCIBLE, Some(true))) };
+    const { assert!(matches!(GF9::IRREDUCIBLE, Some(true))) };
+    const { assert!(matches!(GF::<2, 8, X>::IRREDUCIBLE, Some(true))) };
+    const { assert!(matches!(GF::<3, 4, X>::IRREDUCIBLE, Some(true))) };
+    const { assert!(matches!(GF::<7, 1, X, {&[3, 5]}>::IRREDUCIBLE, Some(true))) };
+    // A reducible modulus fails to compile in any constructor. X^2 + 1 = (X + 1)^2 over F2 and (X + 2)(X + 3) over F5.
+    const { assert!(matches!(GF::<2, 2, X, {&[1, 0, 1]}>::IRREDUCIBLE, Some(false))) };
+    const { assert!(matches!(GF::<5, 2, X, {&[1, 0, 1]}>::IRREDUCIBLE, Some(false))) };
+    // Reducible moduli without roots: X^4 + 1 over F3, (X^2 + X + 1)^2 and (X^3 + X + 1)(X^3 + X^2 + 1) over F2.
+    const { assert!(matches!(GF::<3, 4, X, {&[1, 0, 0, 0, 1]}>::IRREDUCIBLE, Some(false))) };
+    const { assert!(matches!(GF::<2, 4, X, {&[1, 0, 1, 0, 1]}>::IRREDUCIBLE, Some(false))) };
+    const { assert!(matches!(GF::<2, 6, X, {&[1, 1, 1, 1, 1, 1, 1]}>::IRREDUCIBLE, Some(false))) };
+    // Non-monic moduli: 2X^2 + 2X + 2 = 2(X - 1)^2 is reducible over F3 and 2X^2 + 2 = 2(X^2 + 1) is not.
+    const { assert!(matches!(GF::<3, 2, X, {&[2, 2, 2]}>::IRREDUCIBLE, Some(false))) };
+    const { assert!(matches!(GF::<3, 2, X, {&[2, 0, 2]}>::IRREDUCIBLE, Some(true))) };
+}
+
+/// The GCM polynomial `X^128 + X^7 + X^2 + X + 1`.
+const GCM: &[u64] = &{
+    let mut modulus = [0; 129];
+    modulus[0] = 1;
+    modulus[1] = 1;
+    modulus[2] = 1;
+    modulus[7] = 1;
+    modulus[128] = 1;
+    modulus
+};
+
+/// `X^128 + 1 = (X + 1)^128` over F2.
+const REDUCIBLE_128: &[u64] = &{
+    let mut modulus = [0; 129];
+    modulus[0] = 1;
+    modulus[128] = 1;
+    modulus
+};
+
+/// `X^50 + X^4 + X^3 + X^2 + 1`.
+const M50: &[u64] = &{
+    let mut modulus = [0; 51];
+    modulus[0] = 1;
+    modulus[2] = 1;
+    modulus[3] = 1;
+    modulus[4] = 1;
+    modulus[50] = 1;
+    modulus
+};
+
+/// `X^64 + X^4 + X^3 + X + 1`.
+const M64: &[u64] = &{
+    let mut modulus = [0; 65];
+    modulus[0] = 1;
+    modulus[1] = 1;
+    modulus[3] = 1;
+    modulus[4] = 1;
+    modulus[64] = 1;
+    modulus
+};
+
+type GF2_128 = GF<2, 128, X, GCM>;
+
+#[test]
+fn test_gf_large_modulus_is_checked_at_runtime() {
+    // GF(2^64) and GF(2^128) are beyond the compile-time budget, so their moduli are tested on first use instead.
+    const { assert!(GF2_128::IRREDUCIBLE.is_none()) };
+    const { assert!(matches!(GF::<2, 50, X, M50>::IRREDUCIBLE, Some(true))) };
+    const { assert!(GF::<2, 64, X, M64>::IRREDUCIBLE.is_none()) };
+    assert!(GF::<2, 64, X, M64>::try_new(&[F2::one(); 64]).is_ok_and(|x| x != GF::zero()));
+    let x = GF2_128::from_index(2);
+    assert_eq!(x * x.inverse().unwrap(), GF2_128::one());
+    assert_eq!(GF::<2, 128, X, REDUCIBLE_128>::try_new(&[F2::one(); 128]), Err(Error::ReducibleModulus));
+}
+
+#[test]
+#[should_panic(expected = "The modulus of GF(P^N) must be irreducible")]
+fn test_gf_large_reducible_modulus_panics() {
+    GF::<2, 128, X, REDUCIBLE_128>::one();
+}
+
+#[test]
+fn test_gf_defaults_to_conway_polynomial() {
+    type GF256 = GF<2, 8, X>;
+    assert_eq!(GF256::modulus(), conway::conway_polynomial::<2, X>(8));
+    // The Conway polynomial is primitive, so X generates the multiplicative group of order 255 = 3 * 5 * 17.
+    let x = GF256::from_poly(&Polynomial::indeterminant_power(1));
+    let mut power = GF256::one();
+    for i in 1..=255 {
+        power *= x;
+        if [255 / 3, 255 / 5, 255 / 17].contains(&i) {
+            assert_ne!(power, GF::one());
+        }
+    }
+    assert_eq!(power, GF::one());
+    // Naming the default explicitly is the same type.
+    let _same: GF<2, 8, X, CONWAY> = x;
+}
+
+#[test]
+fn test_gf_elements_and_pow() {
+    assert_eq!(GF9::size(), 9);
+    let elements: Vec<GF9> = GF9::elements().collect();
+    assert_eq!(elements.len(), 9);
+    assert_eq!(elements[0], GF::zero());
+    assert_eq!(elements[1], GF::one());
+    assert_eq!(elements[3], gf9(0, 1));
+    // Fermat's little theorem: a^9 = a for every element.
+    for a in elements {
+        assert_eq!(a.pow(9), a);
+    }
+}
+
+#[test]
+fn test_gf_multiplicative_order() {
+    assert_eq!(GF9::zero().multiplicative_order(), None);
+    assert_eq!(GF9::one().multiplicative_order(), Some(1));
+    assert_eq!(gf9(2, 0).multiplicative_order(), Some(2));
+    // X^2 = -1 in GF(9), so X has order 4 and is not primitive.
+    assert_eq!(gf9(0, 1).multiplicative_order(), Some(4));
+    assert!(!gf9(0, 1).is_primitive());
+    // Element orders divide 8, and exactly phi(8) = 4 elements are primitive.
+    let primitive: Vec<GF9> = GF9::elements().filter(GF::is_primitive).collect();
+    assert_eq!(primitive.len(), 4);
+    for a in primitive {
+        assert_eq!(a.multiplicative_order(), Some(8));
+    }
+}
+
+#[test]
+fn test_gf_generator() {
+    // 1 + X is the first primitive element of GF(9) = F3[X]/(X^2 + 1).
+    assert_eq!(GF9::generator(), gf9(1, 1));
+    // The Conway polynomial is primitive, so the generator of GF(2^8) is X.
+    assert_eq!(GF::<2, 8, X>::generator(), GF::from_poly(&Polynomial::indeterminant_power(1)));
+    // GF(8)^* has prime order 7, so every element other than 0 and 1 is primitive.
+    assert_eq!(GF8::elements().filter(GF::is_primitive).count(), 6);
+}
@@ -0,0 +1,364 @@
+//! A table-driven representation of small Galois fields using Zech logarithms.
+//!
+//! A non-zero element is stored as its logarithm `e` to the base of a fixed primitive element `g`, so multiplication
+//! and inversion are additions and negations of exponents modulo `q - 1`. Addition uses the Zech logarithm `Z(n)`,
+//! defined by `g^Z(n) = 1 + g^n`, through `g^i + g^j = g^(i + Z(j - i))`.
+
+use std::{any::TypeId, collections::HashMap, fmt::Debug, hash::Hash, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}, sync::{Mutex, OnceLock}};
+
+use crate::{FiniteField, Field, IndexedField, Indeterminate, Ring};
+
+use super::{CONWAY, GF};
+
+/// The largest field for which tables are built. The tables take 12 bytes per field element.
+pub const MAX_TABLE_SIZE: u128 = 1 << 16;
+
+/// The exponent standing for zero, which is not a power of the primitive element.
+const ZERO: u32 = u32::MAX;
+
+/// Log, antilog and Zech tables for one field, built from its first primitive element `g`.
+struct ZechTables {
+    /// The order of the multiplicative group, `q - 1`.
+    order: u32,
+    /// The logarithm of `-1`.
+    minus_one: u32,
+    /// `log[i]` is the logarithm of the element with `GF::index` `i`, or `ZERO`.
+    log: Vec<u32>,
+    /// `antilog[e]` is the `GF::index` of `g^e`.
+    antilog: Vec<u32>,
+    /// `zech[n]` is the logarithm of `1 + g^n`, or `ZERO`.
+    zech: Vec<u32>,
+}
+
+impl ZechTables {
+    fn new<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]>() -> Self {
+        let size = GF::<P, N, SYMBOL, MODULUS>::size();
+        assert!(size <= MAX_TABLE_SIZE, "Zech tables are only built for fields of at most 2^16 elements");
+        let order = (size - 1) as u32;
+        let g = GF::<P, N, SYMBOL, MODULUS>::generator();
+        let mut log = vec![ZERO; size as usize];
+        let mut antilog = Vec::with_capacity(order as usize);
+        let mut power = GF::one();
+        for e in 0..order {
+            log[power.index() as usize] = e;
+            antilog.push(power.index() as u32);
+            power *= g;
+        }
+        let one = GF::<P, N, SYMBOL, MODULUS>::one();
+        let zech = antilog.iter()
+            .map(|&i| log[(GF::<P, N, SYMBOL, MODULUS>::from_index(i as u128) + one).index() as usize])
+            .collect();
+        let minus_one = log[(-one).index() as usize];
+        Self { order, minus_one, log, antilog, zech }
+    }
+
+    /// The tables for `GF<P, N, SYMBOL, MODULUS>`, built on first use and shared for the rest of the program.
+    fn get<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]>() -> &'static Self {
+        static REGISTRY: OnceLock<Mutex<HashMap<TypeId, &'static ZechTables>>> = OnceLock::new();
+        let mut registry = REGISTRY.get_or_init(Default::default).lock().expect("Zech table registry poisoned");
+        registry
+            .entry(TypeId::of::<GF<P, N, SYMBOL, MODULUS>>())
+            .or_insert_with(|| Box::leak(Box::new(Self::new::<P, N, SYMBOL, MODULUS>())))
+    }
+}
+
+/// An element of `GF<P, N, SYMBOL, MODULUS>` in Zech logarithm representation.
+///
+/// This has the same `Ring`/`Field` behaviour as `GF`, and converts to and from it with `From`. Elements carry a
+/// reference to their field's tables, except those made by `Ring::zero()` and `Ring::one()`, which need no table and
+/// so never touch the shared registry. Arithmetic takes the tables from whichever operand has them, and only looks
+/// them up when neither does.
+#[derive(Clone, Copy)]
+pub struct ZechGF<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64] = CONWAY> {
+    exp: u32,
+    tables: Option<&'static ZechTables>,
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> ZechGF<P, N, SYMBOL, MODULUS> {
+    /// The tables of this element, or of `other` if this element has none, looked up only if neither has them.
+    fn tables_with(&self, other: &Self) -> &'static ZechTables {
+        self.tables.or(other.tables).unwrap_or_else(ZechTables::get::<P, N, SYMBOL, MODULUS>)
+    }
+
+    /// The primitive element `g` that logarithms are taken with respect to, which is `GF::generator()`.
+    pub fn generator() -> Self {
+        let tables = ZechTables::get::<P, N, SYMBOL, MODULUS>();
+        Self {
+            exp: 1 % tables.order,
+            tables: Some(tables)
+        }
+    }
+
+    /// The logarithm of this element to the base `generator()`, or `None` for zero.
+    pub fn log(&self) -> Option<u32> {
+        (self.exp != ZERO).then_some(self.exp)
+    }
+
+    /// Multiplicative inverse, or `None` for zero.
+    pub fn inverse(&self) -> Option<Self> {
+        let exp = self.log()?;
+        if exp == 0 {
+            return Some(*self);
+        }
+        let tables = self.tables_with(self);
+        Some(Self {
+            exp: tables.order - exp,
+            tables: Some(tables)
+        })
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> From<GF<P, N, SYMBOL, MODULUS>> for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn from(value: GF<P, N, SYMBOL, MODULUS>) -> Self {
+        let tables = ZechTables::get::<P, N, SYMBOL, MODULUS>();
+        Self {
+            exp: tables.log[value.index() as usize],
+            tables: Some(tables)
+        }
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> From<ZechGF<P, N, SYMBOL, MODULUS>> for GF<P, N, SYMBOL, MODULUS> {
+    fn from(value: ZechGF<P, N, SYMBOL, MODULUS>) -> Self {
+        match value.log() {
+            Some(0) => GF::one(),
+            Some(exp) => GF::from_index(value.tables_with(&value).antilog[exp as usize] as u128),
+            None => GF::zero(),
+        }
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> PartialEq for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.exp == other.exp
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Eq for ZechGF<P, N, SYMBOL, MODULUS> {}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Hash for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.exp.hash(state);
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Debug for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ZechGF").field("log", &self.log()).finish()
+    }
+}
+
+// Add implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Add for ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (Some(i), Some(j)) = (self.log(), rhs.log()) else {
+            return if self.exp == ZERO { rhs } else { self };
+        };
+        let tables = self.tables_with(&rhs);
+        let order = tables.order;
+        // g^i + g^j = g^i (1 + g^(j - i))
+        let zech = tables.zech[((j + order - i) % order) as usize];
+        Self {
+            exp: if zech == ZERO { ZERO } else { ((i as u64 + zech as u64) % order as u64) as u32 },
+            tables: Some(tables)
+        }
+    }
+}
+
+// Mul implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Mul for ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let tables = self.tables.or(rhs.tables);
+        match (self.log(), rhs.log()) {
+            (None, _) | (_, None) => Self { exp: ZERO, tables },
+            (Some(0), _) => Self { exp: rhs.exp, tables },
+            (_, Some(0)) => Self { exp: self.exp, tables },
+            (Some(i), Some(j)) => {
+                let tables = self.tables_with(&rhs);
+                Self {
+                    exp: ((i as u64 + j as u64) % tables.order as u64) as u32,
+                    tables: Some(tables)
+                }
+            },
+        }
+    }
+}
+
+// Neg implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Neg for ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.exp == ZERO {
+            return self;
+        }
+        let tables = self.tables_with(&self);
+        self * Self { exp: tables.minus_one, tables: Some(tables) }
+    }
+}
+
+// Sub implementation
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Sub for ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+// Div implementation
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Div for ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Attempted to divide by a non-unit")
+    }
+}
+
+// AddAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> AddAssign for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> AddAssign<&ZechGF<P, N, SYMBOL, MODULUS>> for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn add_assign(&mut self, rhs: &ZechGF<P, N, SYMBOL, MODULUS>) {
+        *self = *self + *rhs;
+    }
+}
+
+// SubAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> SubAssign for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> SubAssign<&ZechGF<P, N, SYMBOL, MODULUS>> for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn sub_assign(&mut self, rhs: &ZechGF<P, N, SYMBOL, MODULUS>) {
+        *self = *self - *rhs;
+    }
+}
+
+// MulAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> MulAssign for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> MulAssign<&ZechGF<P, N, SYMBOL, MODULUS>> for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn mul_assign(&mut self, rhs: &ZechGF<P, N, SYMBOL, MODULUS>) {
+        *self = *self * *rhs;
+    }
+}
+
+// DivAssign implementations
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> DivAssign for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> DivAssign<&ZechGF<P, N, SYMBOL, MODULUS>> for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn div_assign(&mut self, rhs: &ZechGF<P, N, SYMBOL, MODULUS>) {
+        *self = *self / *rhs;
+    }
+}
+
+// Reference operations for Ring trait bounds
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Add<&'b ZechGF<P, N, SYMBOL, MODULUS>> for &ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = ZechGF<P, N, SYMBOL, MODULUS>;
+
+    fn add(self, rhs: &'b ZechGF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Mul<&'b ZechGF<P, N, SYMBOL, MODULUS>> for &ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = ZechGF<P, N, SYMBOL, MODULUS>;
+
+    fn mul(self, rhs: &'b ZechGF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Sub<&'b ZechGF<P, N, SYMBOL, MODULUS>> for &ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = ZechGF<P, N, SYMBOL, MODULUS>;
+
+    fn sub(self, rhs: &'b ZechGF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Neg for &ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = ZechGF<P, N, SYMBOL, MODULUS>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<'b, const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Div<&'b ZechGF<P, N, SYMBOL, MODULUS>> for &ZechGF<P, N, SYMBOL, MODULUS> {
+    type Output = ZechGF<P, N, SYMBOL, MODULUS>;
+
+    fn div(self, rhs: &'b ZechGF<P, N, SYMBOL, MODULUS>) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Ring for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn zero() -> Self {
+        Self { exp: ZERO, tables: None }
+    }
+
+    fn one() -> Self {
+        Self { exp: 0, tables: None }
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> Field for ZechGF<P, N, SYMBOL, MODULUS> {
+
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> FiniteField for ZechGF<P, N, SYMBOL, MODULUS> {
+    fn size() -> u128 {
+        GF::<P, N, SYMBOL, MODULUS>::size()
+    }
+
+    fn pow(&self, exp: u128) -> Self {
+        match self.log() {
+            Some(0) => *self,
+            Some(log) => {
+                let tables = self.tables_with(self);
+                Self {
+                    // Reducing `exp` first keeps the product below `order^2`, which fits in a `u128`.
+                    exp: ((log as u128 * (exp % tables.order as u128)) % tables.order as u128) as u32,
+                    tables: Some(tables)
+                }
+            },
+            None if exp == 0 => Self::one(),
+            None => *self,
+        }
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]> IndexedField for ZechGF<P, N, SYMBOL, MODULUS> {
+    /// The same enumeration as `GF::from_index`.
+    fn from_index(index: u128) -> Self {
+        Self::from(GF::from_index(index))
+    }
+
+    fn index(&self) -> u128 {
+        GF::from(*self).index()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::{modulo_ints::IMod, X};
+
+// GF(9) = F3[X]/(X^2 + 1), where X itself is not primitive.
+type GF9 = GF<3, 2, X, {&[1, 0, 1]}>;
+type Zech9 = ZechGF<3, 2, X, {&[1, 0, 1]}>;
+
+/// Every operation on `ZechGF` must agree with the same operation on `GF`.
+fn check_agrees_with_gf<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64]>() {
+    let elements: Vec<GF<P, N, SYMBOL, MODULUS>> = GF::elements().collect();
+    for &a in &elements {
+        let za = ZechGF::from(a);
+        assert_eq!(GF::from(za), a);
+        assert_eq!(GF::from(-za), -a);
+        assert_eq!(za.inverse().map(GF::from), a.inverse());
+        for &b in &elements {
+            let zb = ZechGF::from(b);
+            assert_eq!(GF::from(za + zb), a + b);
+            assert_eq!(GF::from(za - zb), a - b);
+            assert_eq!(GF::from(za * zb), a * b);
+            if b != GF::zero() {
+                assert_eq!(GF::from(za / zb), a / b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_zech_agrees_with_gf() {
+    check_agrees_with_gf::<3, 2, X, {&[1, 0, 1]}>();
+    check_agrees_with_gf::<2, 4, X, CONWAY>();
+    check_agrees_with_gf::<5, 2, X, CONWAY>();
+    check_agrees_with_gf::<2, 1, X, CONWAY>();
+}
+
+#[test]
+fn test_zech_zero_and_one() {
+    assert_eq!(GF9::from(Zech9::zero()), GF::zero());
+    assert_eq!(GF9::from(Zech9::one()), GF::one());
+    assert_eq!(Zech9::zero().log(), None);
+    assert_eq!(Zech9::one().log(), Some(0));
+    assert_eq!(Zech9::zero().inverse(), None);
+}
+
+#[test]
+fn test_zech_zero_and_one_without_tables() {
+    // Constants made by `Ring` carry no tables, and pick them up from the other operand or look them up on demand.
+    assert!(Zech9::zero().tables.is_none() && Zech9::one().tables.is_none());
+    let (zero, one, g) = (Zech9::zero(), Zech9::one(), Zech9::generator());
+    assert_eq!(GF9::from(one + one), GF9::one() + GF9::one());
+    assert_eq!(GF9::from(-one), -GF9::one());
+    assert_eq!(one * one, one);
+    assert_eq!(one.inverse(), Some(one));
+    assert_eq!(one.pow(5), one);
+    assert_eq!(zero * g, zero);
+    assert_eq!(GF9::from(one * g + one), GF9::from(g) + GF9::one());
+    assert_eq!(GF9::from(g - g * one), GF9::zero());
+}
+
+#[test]
+fn test_zech_index_matches_gf() {
+    for i in 0..9 {
+        assert_eq!(GF9::from(Zech9::from_index(i)), GF9::from_index(i));
+        assert_eq!(IndexedField::index(&Zech9::from_index(i)), i);
+    }
+}
+
+#[test]
+fn test_zech_generator_and_pow() {
+    // Logarithms are taken to the base of GF::generator().
+    assert_eq!(GF9::from(Zech9::generator()), GF9::generator());
+    let g = Zech9::generator();
+    for e in 0..8 {
+        assert_eq!(g.pow(e).log(), Some(e as u32));
+        assert_eq!(GF9::from(g.pow(e)), GF9::generator().pow(e));
+    }
+    assert_eq!(g.pow(8), Zech9::one());
+    assert_eq!(Zech9::zero().pow(0), Zech9::one());
+    assert_eq!(Zech9::zero().pow(3), Zech9::zero());
+    assert_eq!(g.pow(2).multiplicative_order(), Some(4));
+    // Exponents are reduced before multiplying logarithms, so the largest exponent neither overflows nor wraps.
+    assert_eq!(GF9::from(g.pow(u128::MAX)), GF9::generator().pow(u128::MAX));
+    assert_eq!(g.pow(u128::MAX).log(), Some((u128::MAX % 8) as u32));
+    type Zech256 = ZechGF<2, 8, X>;
+    let h = Zech256::generator().pow(2);
+    assert_eq!(GF::from(h.pow(u128::MAX)), GF::<2, 8, X>::from(h).pow(u128::MAX));
+}
+
+#[test]
+fn test_zech_assign_operations() {
+    let a = Zech9::from(GF9::new(&[IMod::new(1), IMod::new(2)]));
+    let b = Zech9::from(GF9::new(&[IMod::new(2), IMod::new(2)]));
+    let mut c = a;
+    c += b;
+    c -= &b;
+    assert_eq!(c, a);
+    c *= b;
+    c /= &b;
+    assert_eq!(c, a);
+}
+
+#[test]
+#[should_panic(expected = "non-unit")]
+fn test_zech_division_by_zero() {
+    let _result = Zech9::one() / Zech9::zero();
+}
@@ -1,12 +1,14 @@
-#![feature(adt_const_params)]
+#![feature(adt_const_params, unsized_const_params)]
+#![allow(incomplete_features)]
 // This module's core logic doesn't seem to work in the current version of Rust, but I'll leave this around in case it does in the future.
-use std::{fmt::Display, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
+use std::{error, fmt::{self, Display}, hash::Hash, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign}};
 use std::marker::ConstParamTy;
 
 pub mod polynomial;
 pub mod modulo_ints;
 pub mod tools;
 pub mod galois;
+pub mod discrete_log;
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ConstParamTy)]
 pub struct Indeterminate {
@@ -22,11 +24,44 @@ impl Indeterminate {
 }
 
 impl Display for Indeterminate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f,"{}", self.name)
     }
 }
 
+/// The reason a checked operation such as `checked_div`, `try_inverse` or `try_new` failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// Dividing by, reducing modulo or inverting zero.
+    DivisionByZero,
+    /// Inverting a non-zero element that has no multiplicative inverse. Only polynomials and `ZMod` residues sharing a
+    /// factor with a composite `BASE` return this: every non-zero element of a field such as `IMod` or `GF` is a unit.
+    NonUnit,
+    /// A field modulus that is not irreducible.
+    ReducibleModulus,
+    /// A value of length or degree `found` where `expected` is required.
+    DegreeMismatch { expected: usize, found: usize },
+}
+
+// Display implementation
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DivisionByZero => write!(f, "division by zero"),
+            Self::NonUnit => write!(f, "the element is not a unit"),
+            Self::ReducibleModulus => write!(f, "the modulus is reducible"),
+            Self::DegreeMismatch { expected, found } => write!(f, "expected degree {expected}, found {found}"),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Rendering as LaTeX math-mode source, without the surrounding `$` delimiters.
+pub trait Latex {
+    fn to_latex(&self) -> String;
+}
+
 pub const T: Indeterminate = Indeterminate::new('T');
 pub const X: Indeterminate = Indeterminate::new('X');
 pub const Y: Indeterminate = Indeterminate::new('Y');
@@ -39,6 +74,14 @@ where
     fn zero() -> Self;
 
     fn one() -> Self;
+
+    /// The coefficients of the product of two polynomials, each given lowest power first.
+    ///
+    /// This is Karatsuba's algorithm unless overridden; rings with a faster method from `polynomial::multiplication`
+    /// available, such as Toom-3 or the number-theoretic transform, use it here.
+    fn multiply_coefficients(a: &[Self], b: &[Self]) -> Vec<Self> {
+        polynomial::multiplication::karatsuba(a, b)
+    }
 }
 
 pub trait Field: Ring + Div + DivAssign + for<'a> DivAssign<&'a Self>
@@ -46,6 +89,74 @@ where
     for<'a, 'b> &'a Self : Add<&'b Self, Output = Self> + Mul<&'b Self, Output = Self> + Sub<&'b Self, Output = Self> + Neg<Output = Self> + Div<&'b Self, Output = Self>
 {}
 
+/// A field with finitely many elements. Its multiplicative group is cyclic of order `group_order()`.
+pub trait FiniteField: Field + Copy + Hash
+where
+    for<'a, 'b> &'a Self : Add<&'b Self, Output = Self> + Mul<&'b Self, Output = Self> + Sub<&'b Self, Output = Self> + Neg<Output = Self> + Div<&'b Self, Output = Self>
+{
+    /// The number of elements in the field.
+    fn size() -> u128;
+
+    /// The order `size() - 1` of the multiplicative group. Unlike `size()`, this fits in a `u128` for every field of
+    /// at most `2^128` elements, so generic code that only needs the group order should call this instead.
+    fn group_order() -> u128 {
+        Self::size() - 1
+    }
+
+    /// `self^exp`, by repeated squaring.
+    fn pow(&self, mut exp: u128) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result *= &base;
+            }
+            exp >>= 1;
+            base *= base;
+        }
+        result
+    }
+
+    /// The order of this element in the multiplicative group, or `None` for zero.
+    ///
+    /// Starting from the group order `group_order()`, each prime factor is divided out for as long as the element still
+    /// raises to one.
+    fn multiplicative_order(&self) -> Option<u128> {
+        if *self == Self::zero() {
+            return None;
+        }
+        let mut order = Self::group_order();
+        for (prime, _) in tools::factorize(order) {
+            while order.is_multiple_of(prime) && self.pow(order / prime) == Self::one() {
+                order /= prime;
+            }
+        }
+        Some(order)
+    }
+
+    /// Whether this element generates the multiplicative group of the field.
+    fn is_primitive(&self) -> bool {
+        if *self == Self::zero() {
+            return false;
+        }
+        let order = Self::group_order();
+        tools::factorize(order).into_iter().all(|(prime, _)| self.pow(order / prime) != Self::one())
+    }
+}
+
+/// A finite field with a fixed enumeration of its elements, which root finding uses to draw random elements and to
+/// sort its results. This is kept apart from `FiniteField` so that implementing that trait needs no enumeration.
+pub trait IndexedField: FiniteField
+where
+    for<'a, 'b> &'a Self : Add<&'b Self, Output = Self> + Mul<&'b Self, Output = Self> + Sub<&'b Self, Output = Self> + Neg<Output = Self> + Div<&'b Self, Output = Self>
+{
+    /// The element at position `index < size()` in a fixed enumeration of the field, starting from zero.
+    fn from_index(index: u128) -> Self;
+
+    /// The position of this element in the enumeration of `from_index`.
+    fn index(&self) -> u128;
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused)]
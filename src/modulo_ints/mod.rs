@@ -1,18 +1,42 @@
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign, Neg, Div, DivAssign};
+use std::{fmt::{self, Display}, num::ParseIntError, ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign, Neg, Div, DivAssign}, str::FromStr};
 
-use crate::{Field, Ring};
+use crate::{Error, FiniteField, Field, IndexedField, Latex, Ring};
 
+pub mod montgomery;
+pub mod zmod;
+
+/// An element of the prime field `Z/BASE`.
+///
+/// Using `IMod<BASE>` with a composite `BASE` fails to compile, since it implements `Field`. `zmod::ZMod` is the ring
+/// `Z/nZ` for any modulus.
 #[derive(Clone, Copy, Debug)]
 pub struct IMod<const BASE: u64> {
     val: u128
 }
 
 impl<const BASE: u64> IMod<BASE> {
+    /// `BASE`, used by every constructor so that a non-prime `BASE` is rejected at compile time.
+    const PRIME_BASE: u128 = {
+        assert!(crate::tools::is_prime(BASE as u128), "IMod<BASE> requires a prime BASE; use ZMod<BASE> for composite moduli");
+        BASE as u128
+    };
+
     pub const fn new(val:u128) -> Self {
         Self {
-            val: (val%(BASE as u128))
+            val: (val%Self::PRIME_BASE)
         }
     }
+
+    /// `new`, for use alongside the other checked constructors. It never fails, since every `u128` reduces to a
+    /// residue and a composite `BASE` already fails to compile.
+    pub const fn try_new(val: u128) -> Result<Self, Error> {
+        Ok(Self::new(val))
+    }
+
+    /// The canonical representative of this residue, in `0..BASE`.
+    pub const fn value(self) -> u128 {
+        self.val
+    }
 }
 impl<const BASE: u64> Add for IMod<BASE> {
     type Output = Self;
@@ -110,9 +134,28 @@ impl<const BASE: u64> IMod<BASE> {
         }
     }
 
+    /// The inverse, or `Error::DivisionByZero` for zero. Since `BASE` is prime, this never fails with
+    /// `Error::NonUnit`.
+    pub const fn try_inverse(self) -> Result<Self, Error> {
+        match self.inverse() {
+            Some(inverse) => Ok(inverse),
+            None => Err(Error::DivisionByZero),
+        }
+    }
+
+    /// `self / rhs`, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, Error> {
+        rhs.try_inverse().map(|inverse| self * inverse)
+    }
+
+    /// The remainder of `self` by `rhs`, which is always zero in a field, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, Error> {
+        self.checked_div(rhs).map(|_| Self::zero())
+    }
 }
 
 // Div implementation
+#[allow(clippy::suspicious_arithmetic_impl)]
 impl<const BASE: u64> Div for IMod<BASE> {
     type Output = Self;
 
@@ -143,8 +186,14 @@ impl<const BASE: u64> PartialEq for IMod<BASE> {
 
 impl<const BASE: u64> Eq for IMod<BASE> {}
 
+impl<const BASE: u64> std::hash::Hash for IMod<BASE> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
 // Reference operations for Ring trait bounds
-impl<'a, 'b, const BASE: u64> Add<&'b IMod<BASE>> for &'a IMod<BASE> {
+impl<'b, const BASE: u64> Add<&'b IMod<BASE>> for &IMod<BASE> {
     type Output = IMod<BASE>;
 
     fn add(self, rhs: &'b IMod<BASE>) -> Self::Output {
@@ -152,7 +201,7 @@ impl<'a, 'b, const BASE: u64> Add<&'b IMod<BASE>> for &'a IMod<BASE> {
     }
 }
 
-impl<'a, 'b, const BASE: u64> Mul<&'b IMod<BASE>> for &'a IMod<BASE> {
+impl<'b, const BASE: u64> Mul<&'b IMod<BASE>> for &IMod<BASE> {
     type Output = IMod<BASE>;
 
     fn mul(self, rhs: &'b IMod<BASE>) -> Self::Output {
@@ -160,7 +209,7 @@ impl<'a, 'b, const BASE: u64> Mul<&'b IMod<BASE>> for &'a IMod<BASE> {
     }
 }
 
-impl<'a, 'b, const BASE: u64> Sub<&'b IMod<BASE>> for &'a IMod<BASE> {
+impl<'b, const BASE: u64> Sub<&'b IMod<BASE>> for &IMod<BASE> {
     type Output = IMod<BASE>;
 
     fn sub(self, rhs: &'b IMod<BASE>) -> Self::Output {
@@ -168,7 +217,7 @@ impl<'a, 'b, const BASE: u64> Sub<&'b IMod<BASE>> for &'a IMod<BASE> {
     }
 }
 
-impl<'a, const BASE: u64> Neg for &'a IMod<BASE> {
+impl<const BASE: u64> Neg for &IMod<BASE> {
     type Output = IMod<BASE>;
 
     fn neg(self) -> Self::Output {
@@ -184,9 +233,13 @@ impl<const BASE: u64> Ring for IMod<BASE> {
     fn one() -> Self {
         Self::new(1)
     }
+
+    fn multiply_coefficients(a: &[Self], b: &[Self]) -> Vec<Self> {
+        crate::polynomial::multiplication::multiply_imod(a, b)
+    }
 }
 
-impl<'a, 'b, const BASE: u64> Div<&'b IMod<BASE>> for &'a IMod<BASE> {
+impl<'b, const BASE: u64> Div<&'b IMod<BASE>> for &IMod<BASE> {
     type Output = IMod<BASE>;
 
     fn div(self, rhs: &'b IMod<BASE>) -> Self::Output {
@@ -198,5 +251,49 @@ impl<const BASE: u64> Field for IMod<BASE> {
     
 }
 
+impl<const BASE: u64> FiniteField for IMod<BASE> {
+    fn size() -> u128 {
+        BASE as u128
+    }
+}
+
+impl<const BASE: u64> IndexedField for IMod<BASE> {
+    fn from_index(index: u128) -> Self {
+        Self::new(index)
+    }
+
+    fn index(&self) -> u128 {
+        self.value()
+    }
+}
+
+// Display implementation
+impl<const BASE: u64> Display for IMod<BASE> {
+    /// The canonical representative in `0..BASE`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+// Latex implementation
+impl<const BASE: u64> Latex for IMod<BASE> {
+    fn to_latex(&self) -> String {
+        self.val.to_string()
+    }
+}
+
+// FromStr implementation
+impl<const BASE: u64> FromStr for IMod<BASE> {
+    type Err = ParseIntError;
+
+    /// An integer, possibly negative, reduced modulo `BASE`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(magnitude) => magnitude.parse().map(|x| -Self::new(x)),
+            None => s.parse().map(Self::new),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test;
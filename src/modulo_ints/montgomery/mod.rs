@@ -0,0 +1,261 @@
+//! Modular integers in Montgomery form.
+//!
+//! A residue `a` is stored as `a R mod BASE` with `R = 2^64`, so that a product only needs Montgomery reduction
+//! (two word multiplications and a shift) instead of a `u128` division by `BASE`. Like `IMod`, `BASE` must be prime,
+//! and it must also be odd.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{FiniteField, Field, IndexedField, Ring};
+
+use super::IMod;
+
+/// The residue `a mod BASE`, stored as `a 2^64 mod BASE`.
+///
+/// Behaves exactly like `IMod<BASE>`, and converting between the two costs a single multiplication.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Montgomery<const BASE: u64> {
+    mont: u64
+}
+
+impl<const BASE: u64> Montgomery<BASE> {
+    /// `-BASE^-1 mod 2^64`, found by Newton's iteration, which doubles the number of correct low bits each step.
+    const BASE_NEG_INV: u64 = {
+        assert!(BASE % 2 == 1 && crate::tools::is_prime(BASE as u128), "Montgomery<BASE> requires an odd prime BASE");
+        // BASE * BASE = 1 mod 8 for odd BASE, so this starts with 3 correct bits.
+        let mut inverse = BASE;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(BASE.wrapping_mul(inverse)));
+            i += 1;
+        }
+        inverse.wrapping_neg()
+    };
+
+    /// `R^2 mod BASE`, used to move into Montgomery form.
+    const R_SQUARED: u64 = {
+        let r = (1u128 << 64) % BASE as u128;
+        ((r * r) % BASE as u128) as u64
+    };
+
+    /// Montgomery reduction: `t R^-1 mod BASE`, for `t < BASE 2^64`.
+    const fn reduce(t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(Self::BASE_NEG_INV);
+        // t + m BASE is divisible by 2^64 but may need 129 bits.
+        let (sum, carry) = t.overflowing_add(m as u128 * BASE as u128);
+        let reduced = (sum >> 64) | ((carry as u128) << 64);
+        if reduced >= BASE as u128 { (reduced - BASE as u128) as u64 } else { reduced as u64 }
+    }
+
+    pub const fn new(val: u128) -> Self {
+        Self {
+            mont: Self::reduce((val % BASE as u128) * Self::R_SQUARED as u128)
+        }
+    }
+
+    /// The canonical representative of this residue, in `0..BASE`.
+    pub const fn value(self) -> u128 {
+        Self::reduce(self.mont as u128) as u128
+    }
+
+    pub fn inverse(self) -> Option<Self> {
+        IMod::<BASE>::from(self).inverse().map(Self::from)
+    }
+}
+
+impl<const BASE: u64> From<IMod<BASE>> for Montgomery<BASE> {
+    fn from(value: IMod<BASE>) -> Self {
+        Self::new(value.value())
+    }
+}
+
+impl<const BASE: u64> From<Montgomery<BASE>> for IMod<BASE> {
+    fn from(value: Montgomery<BASE>) -> Self {
+        IMod::new(value.value())
+    }
+}
+
+// Add implementation
+impl<const BASE: u64> Add for Montgomery<BASE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, carry) = self.mont.overflowing_add(rhs.mont);
+        Self {
+            mont: if carry || sum >= BASE { sum.wrapping_sub(BASE) } else { sum }
+        }
+    }
+}
+
+// Mul implementation
+impl<const BASE: u64> Mul for Montgomery<BASE> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            mont: Self::reduce(self.mont as u128 * rhs.mont as u128)
+        }
+    }
+}
+
+// Sub implementation
+impl<const BASE: u64> Sub for Montgomery<BASE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            mont: if self.mont >= rhs.mont { self.mont - rhs.mont } else { self.mont.wrapping_sub(rhs.mont).wrapping_add(BASE) }
+        }
+    }
+}
+
+// Neg implementation
+impl<const BASE: u64> Neg for Montgomery<BASE> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            mont: if self.mont == 0 { 0 } else { BASE - self.mont }
+        }
+    }
+}
+
+// Div implementation
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<const BASE: u64> Div for Montgomery<BASE> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse().expect("Attempted to divide by a non-unit")
+    }
+}
+
+// AddAssign implementations
+impl<const BASE: u64> AddAssign for Montgomery<BASE> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const BASE: u64> AddAssign<&Montgomery<BASE>> for Montgomery<BASE> {
+    fn add_assign(&mut self, rhs: &Montgomery<BASE>) {
+        *self = *self + *rhs;
+    }
+}
+
+// SubAssign implementations
+impl<const BASE: u64> SubAssign for Montgomery<BASE> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const BASE: u64> SubAssign<&Montgomery<BASE>> for Montgomery<BASE> {
+    fn sub_assign(&mut self, rhs: &Montgomery<BASE>) {
+        *self = *self - *rhs;
+    }
+}
+
+// MulAssign implementations
+impl<const BASE: u64> MulAssign for Montgomery<BASE> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const BASE: u64> MulAssign<&Montgomery<BASE>> for Montgomery<BASE> {
+    fn mul_assign(&mut self, rhs: &Montgomery<BASE>) {
+        *self = *self * *rhs;
+    }
+}
+
+// DivAssign implementations
+impl<const BASE: u64> DivAssign for Montgomery<BASE> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const BASE: u64> DivAssign<&Montgomery<BASE>> for Montgomery<BASE> {
+    fn div_assign(&mut self, rhs: &Montgomery<BASE>) {
+        *self = *self / *rhs;
+    }
+}
+
+// Reference operations for Ring trait bounds
+impl<'b, const BASE: u64> Add<&'b Montgomery<BASE>> for &Montgomery<BASE> {
+    type Output = Montgomery<BASE>;
+
+    fn add(self, rhs: &'b Montgomery<BASE>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'b, const BASE: u64> Mul<&'b Montgomery<BASE>> for &Montgomery<BASE> {
+    type Output = Montgomery<BASE>;
+
+    fn mul(self, rhs: &'b Montgomery<BASE>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'b, const BASE: u64> Sub<&'b Montgomery<BASE>> for &Montgomery<BASE> {
+    type Output = Montgomery<BASE>;
+
+    fn sub(self, rhs: &'b Montgomery<BASE>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const BASE: u64> Neg for &Montgomery<BASE> {
+    type Output = Montgomery<BASE>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<'b, const BASE: u64> Div<&'b Montgomery<BASE>> for &Montgomery<BASE> {
+    type Output = Montgomery<BASE>;
+
+    fn div(self, rhs: &'b Montgomery<BASE>) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+impl<const BASE: u64> Ring for Montgomery<BASE> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn multiply_coefficients(a: &[Self], b: &[Self]) -> Vec<Self> {
+        crate::polynomial::multiplication::toom3(a, b)
+    }
+}
+
+impl<const BASE: u64> Field for Montgomery<BASE> {
+
+}
+
+impl<const BASE: u64> FiniteField for Montgomery<BASE> {
+    fn size() -> u128 {
+        BASE as u128
+    }
+}
+
+impl<const BASE: u64> IndexedField for Montgomery<BASE> {
+    fn from_index(index: u128) -> Self {
+        Self::new(index)
+    }
+
+    fn index(&self) -> u128 {
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,73 @@
+use super::*;
+
+/// The largest prime below 2^64, where sums and Montgomery reductions overflow a `u64`.
+const LARGE: u64 = 18_446_744_073_709_551_557;
+
+/// Checks every operation against `IMod` on a spread of residues.
+fn check_against_imod<const BASE: u64>() {
+    let samples: Vec<u128> = [0, 1, 2, 3, BASE as u128 / 2, BASE as u128 - 2, BASE as u128 - 1, 0x1234_5678_9abc_def0, u64::MAX as u128]
+        .into_iter()
+        .map(|x| x % BASE as u128)
+        .collect();
+    for &a in &samples {
+        let (x, mx) = (IMod::<BASE>::new(a), Montgomery::<BASE>::new(a));
+        assert_eq!(mx.value(), x.value());
+        assert_eq!(IMod::from(mx), x);
+        assert_eq!(Montgomery::from(x), mx);
+        assert_eq!((-mx).value(), (-x).value());
+        for &b in &samples {
+            let (y, my) = (IMod::<BASE>::new(b), Montgomery::<BASE>::new(b));
+            assert_eq!((mx + my).value(), (x + y).value());
+            assert_eq!((mx - my).value(), (x - y).value());
+            assert_eq!((mx * my).value(), (x * y).value());
+            if b != 0 {
+                assert_eq!((mx / my).value(), (x / y).value());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_montgomery_constants() {
+    assert_eq!(Montgomery::<7>::BASE_NEG_INV.wrapping_mul(7), u64::MAX);
+    assert_eq!(Montgomery::<LARGE>::BASE_NEG_INV.wrapping_mul(LARGE), u64::MAX);
+    // 2^64 = 2 mod 7, so R^2 = 4.
+    assert_eq!(Montgomery::<7>::R_SQUARED, 4);
+}
+
+#[test]
+fn test_montgomery_matches_imod() {
+    check_against_imod::<3>();
+    check_against_imod::<7>();
+    check_against_imod::<998_244_353>();
+    check_against_imod::<{ (1 << 61) - 1 }>();
+    check_against_imod::<LARGE>();
+}
+
+#[test]
+fn test_montgomery_ring_and_field() {
+    type M = Montgomery<101>;
+    assert_eq!(M::zero().value(), 0);
+    assert_eq!(M::one().value(), 1);
+    assert_eq!(M::new(205).value(), 3);
+    // Fermat's little theorem.
+    assert_eq!(M::new(2).pow(100), M::one());
+    assert_eq!(M::new(3).inverse().map(M::value), Some(34));
+    assert!(M::zero().inverse().is_none());
+
+    let mut a = M::new(50);
+    a += M::new(60);
+    assert_eq!(a.value(), 9);
+    a -= &M::new(10);
+    assert_eq!(a.value(), 100);
+    a *= &M::new(2);
+    assert_eq!(a.value(), 99);
+    a /= M::new(99);
+    assert_eq!(a, M::one());
+}
+
+#[test]
+#[should_panic(expected = "non-unit")]
+fn test_montgomery_division_by_zero() {
+    let _result = Montgomery::<11>::one() / Montgomery::zero();
+}
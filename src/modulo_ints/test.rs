@@ -1,3 +1,5 @@
+#![allow(clippy::op_ref)]
+
 use super::*;
 
 #[test]
@@ -72,6 +74,18 @@ fn test_imod_inverse() {
     assert_eq!(product.val, 1); // Should be multiplicative identity
 }
 
+#[test]
+fn test_imod_checked() {
+    type F7 = IMod<7>;
+    assert_eq!(F7::new(3).try_inverse(), Ok(F7::new(5)));
+    assert_eq!(F7::zero().try_inverse(), Err(crate::Error::DivisionByZero));
+    assert_eq!(F7::new(3).checked_div(F7::new(2)), Ok(F7::new(5)));
+    assert_eq!(F7::new(3).checked_div(F7::new(7)), Err(crate::Error::DivisionByZero));
+    assert_eq!(F7::new(3).checked_rem(F7::new(2)), Ok(F7::zero()));
+    assert_eq!(F7::new(3).checked_rem(F7::zero()), Err(crate::Error::DivisionByZero));
+    assert_eq!(F7::try_new(10), Ok(F7::new(3)));
+}
+
 #[test]
 fn test_imod_zero_and_one() {
     type F5 = IMod<5>;
@@ -0,0 +1,178 @@
+//! The ring of integers modulo an arbitrary `BASE`.
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{tools::mod_inverse, Error, Ring};
+
+use super::IMod;
+
+/// An element of `Z/nZ` with `n = BASE`, which may be composite.
+///
+/// This only implements `Ring`; elements sharing a factor with `BASE` have no inverse, so `try_inverse` fails with
+/// `Error::NonUnit` for them.
+/// `IMod<BASE>` is the field for prime `BASE`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ZMod<const BASE: u64> {
+    val: u128
+}
+
+impl<const BASE: u64> ZMod<BASE> {
+    /// `BASE`, used by every constructor so that `ZMod<0>` is rejected at compile time.
+    const NONZERO_BASE: u128 = {
+        assert!(BASE > 0, "ZMod<BASE> requires a positive BASE");
+        BASE as u128
+    };
+
+    pub const fn new(val: u128) -> Self {
+        Self {
+            val: val % Self::NONZERO_BASE
+        }
+    }
+
+    /// The canonical representative of this residue, in `0..BASE`.
+    pub const fn value(self) -> u128 {
+        self.val
+    }
+
+    /// The inverse of this residue, `Error::DivisionByZero` for zero, or `Error::NonUnit` if it shares a factor with
+    /// `BASE`.
+    pub const fn try_inverse(self) -> Result<Self, Error> {
+        match mod_inverse(self.val as u64, BASE) {
+            Some(inverse) => Ok(Self::new(inverse as u128)),
+            None if self.val == 0 => Err(Error::DivisionByZero),
+            None => Err(Error::NonUnit),
+        }
+    }
+
+    /// Whether this residue has an inverse.
+    pub const fn is_unit(self) -> bool {
+        self.try_inverse().is_ok()
+    }
+}
+
+impl<const BASE: u64> From<IMod<BASE>> for ZMod<BASE> {
+    fn from(value: IMod<BASE>) -> Self {
+        Self::new(value.value())
+    }
+}
+
+// Add implementation
+impl<const BASE: u64> Add for ZMod<BASE> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.val + rhs.val)
+    }
+}
+
+// Mul implementation
+impl<const BASE: u64> Mul for ZMod<BASE> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.val * rhs.val)
+    }
+}
+
+// Sub implementation
+impl<const BASE: u64> Sub for ZMod<BASE> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.val + (BASE as u128 - rhs.val))
+    }
+}
+
+// Neg implementation
+impl<const BASE: u64> Neg for ZMod<BASE> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(BASE as u128 - self.val)
+    }
+}
+
+// AddAssign implementations
+impl<const BASE: u64> AddAssign for ZMod<BASE> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const BASE: u64> AddAssign<&ZMod<BASE>> for ZMod<BASE> {
+    fn add_assign(&mut self, rhs: &ZMod<BASE>) {
+        *self = *self + *rhs;
+    }
+}
+
+// SubAssign implementations
+impl<const BASE: u64> SubAssign for ZMod<BASE> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const BASE: u64> SubAssign<&ZMod<BASE>> for ZMod<BASE> {
+    fn sub_assign(&mut self, rhs: &ZMod<BASE>) {
+        *self = *self - *rhs;
+    }
+}
+
+// MulAssign implementations
+impl<const BASE: u64> MulAssign for ZMod<BASE> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const BASE: u64> MulAssign<&ZMod<BASE>> for ZMod<BASE> {
+    fn mul_assign(&mut self, rhs: &ZMod<BASE>) {
+        *self = *self * *rhs;
+    }
+}
+
+// Reference operations for Ring trait bounds
+impl<'b, const BASE: u64> Add<&'b ZMod<BASE>> for &ZMod<BASE> {
+    type Output = ZMod<BASE>;
+
+    fn add(self, rhs: &'b ZMod<BASE>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<'b, const BASE: u64> Mul<&'b ZMod<BASE>> for &ZMod<BASE> {
+    type Output = ZMod<BASE>;
+
+    fn mul(self, rhs: &'b ZMod<BASE>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<'b, const BASE: u64> Sub<&'b ZMod<BASE>> for &ZMod<BASE> {
+    type Output = ZMod<BASE>;
+
+    fn sub(self, rhs: &'b ZMod<BASE>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<const BASE: u64> Neg for &ZMod<BASE> {
+    type Output = ZMod<BASE>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<const BASE: u64> Ring for ZMod<BASE> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod test;
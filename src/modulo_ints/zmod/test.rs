@@ -0,0 +1,53 @@
+use super::*;
+use crate::polynomial::Polynomial;
+use crate::X;
+
+#[test]
+fn test_zmod_arithmetic() {
+    type Z12 = ZMod<12>;
+    assert_eq!(Z12::new(25).value(), 1);
+    assert_eq!((Z12::new(7) + Z12::new(8)).value(), 3);
+    assert_eq!((Z12::new(3) - Z12::new(5)).value(), 10);
+    assert_eq!((Z12::new(4) * Z12::new(6)).value(), 0);
+    assert_eq!((-Z12::new(5)).value(), 7);
+    assert_eq!(-Z12::zero(), Z12::zero());
+
+    let mut a = Z12::new(5);
+    a += &Z12::new(9);
+    a *= Z12::new(5);
+    a -= Z12::one();
+    assert_eq!(a.value(), 9);
+}
+
+#[test]
+fn test_zmod_try_inverse() {
+    type Z12 = ZMod<12>;
+    // The units of Z/12 are 1, 5, 7 and 11, each its own inverse.
+    for a in 0..12 {
+        let x = Z12::new(a);
+        match x.try_inverse() {
+            Ok(inverse) => {
+                assert!([1, 5, 7, 11].contains(&a));
+                assert_eq!(x * inverse, Z12::one());
+            }
+            Err(_) => assert!(!x.is_unit()),
+        }
+    }
+    assert_eq!(ZMod::<9>::new(2).try_inverse(), Ok(ZMod::new(5)));
+    assert_eq!(ZMod::<9>::new(6).try_inverse(), Err(crate::Error::NonUnit));
+    assert_eq!(ZMod::<9>::zero().try_inverse(), Err(crate::Error::DivisionByZero));
+}
+
+#[test]
+fn test_zmod_polynomials() {
+    // Polynomials only need a Ring: (1 + X)^2 = 1 + 2X + X^2 over Z/4, and (2X)^2 = 0.
+    let one_plus_x: Polynomial<ZMod<4>, X> = Polynomial::from_coeffs(&[ZMod::new(1), ZMod::new(1)]);
+    assert_eq!(&one_plus_x * &one_plus_x, Polynomial::from_coeffs(&[ZMod::new(1), ZMod::new(2), ZMod::new(1)]));
+    let two_x: Polynomial<ZMod<4>, X> = Polynomial::from_coeffs(&[ZMod::new(0), ZMod::new(2)]);
+    assert_eq!(&two_x * &two_x, Polynomial::zero());
+}
+
+#[test]
+fn test_zmod_from_imod() {
+    assert_eq!(ZMod::from(IMod::<7>::new(10)), ZMod::<7>::new(3));
+}
@@ -0,0 +1,494 @@
+//! A dense polynomial representation, storing every coefficient up to the degree in a `Vec`.
+//!
+//! `Polynomial` suits polynomials with few non-zero terms, like the trinomial moduli of binary fields. Most
+//! arithmetic, such as reduction in `GF` or products of random polynomials, fills in every coefficient anyway, and
+//! then a `Vec` indexed by power avoids hashing and keeps `deg` constant time.
+
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use crate::{Field, Indeterminate, Ring};
+
+use super::{division::{self, Modulus}, Polynomial};
+
+#[derive(Debug)]
+pub struct DensePolynomial<R: Ring, const VAR: Indeterminate>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    /// `coeffs[i]` is the coefficient of `VAR^i`. The last entry is never zero, so the zero polynomial is empty.
+    coeffs: Vec<R>,
+}
+
+impl<R, const VAR: Indeterminate> DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    /// Builds a polynomial from its coefficients, where `coeffs[i]` is the coefficient of `VAR^i`.
+    pub fn new(coeffs: Vec<R>) -> Self {
+        let mut out = Self {
+            coeffs
+        };
+        out.prune_zeros();
+        out
+    }
+
+    pub fn from_coeffs(coeffs: &[R]) -> Self {
+        Self::new(coeffs.to_vec())
+    }
+
+    fn prune_zeros(&mut self) {
+        while self.coeffs.last().is_some_and(|x| x == &R::zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    pub fn deg(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn leading(&self) -> Option<&R> {
+        self.coeffs.last()
+    }
+
+    pub fn indeterminant_power(pow: usize) -> Self {
+        let mut coeffs = vec![R::zero(); pow + 1];
+        coeffs[pow] = R::one();
+        Self {
+            coeffs
+        }
+    }
+
+    /// The coefficient of `VAR^i`, which is zero beyond the degree.
+    pub fn coeff(&self, i: usize) -> R {
+        self.coeffs.get(i).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// The coefficients from `VAR^0` up to the leading one.
+    pub fn coeffs(&self) -> &[R] {
+        &self.coeffs
+    }
+
+    /// The formal derivative.
+    pub fn derivative(&self) -> Self {
+        let mut index = R::zero();
+        Self::new(self.coeffs.iter().skip(1).map(|c| {
+            index += R::one();
+            &index * c
+        }).collect())
+    }
+}
+
+impl<R, const VAR: Indeterminate> Clone for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn clone(&self) -> Self {
+        Self {
+            coeffs: self.coeffs.clone()
+        }
+    }
+}
+
+// Conversions to and from the sparse representation
+impl<'a, R, const VAR: Indeterminate> From<&'a Polynomial<R, VAR>> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn from(poly: &'a Polynomial<R, VAR>) -> Self {
+        let mut coeffs = vec![R::zero(); poly.deg().map_or(0, |deg| deg + 1)];
+        for (&i, x) in &poly.vals {
+            coeffs[i] = x.clone();
+        }
+        Self::new(coeffs)
+    }
+}
+
+impl<R, const VAR: Indeterminate> From<Polynomial<R, VAR>> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn from(poly: Polynomial<R, VAR>) -> Self {
+        Self::from(&poly)
+    }
+}
+
+impl<'a, R, const VAR: Indeterminate> From<&'a DensePolynomial<R, VAR>> for Polynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn from(poly: &'a DensePolynomial<R, VAR>) -> Self {
+        Polynomial::from_coeffs(&poly.coeffs)
+    }
+}
+
+impl<R, const VAR: Indeterminate> From<DensePolynomial<R, VAR>> for Polynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn from(poly: DensePolynomial<R, VAR>) -> Self {
+        Self::from(&poly)
+    }
+}
+
+impl<'b, R, const VAR: Indeterminate> Add<&'b DensePolynomial<R, VAR>> for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'd> &'c R : Add<&'d R, Output = R> + Mul<&'d R, Output = R> + Sub<&'d R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn add(self, rhs: &'b DensePolynomial<R, VAR>) -> Self::Output {
+        let mut out = self.clone();
+        out += rhs;
+        out
+    }
+}
+
+impl<R, const VAR: Indeterminate> Add for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Mul<&'c DensePolynomial<R, VAR>> for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn mul(self, rhs: &'c DensePolynomial<R, VAR>) -> Self::Output {
+        DensePolynomial::new(R::multiply_coefficients(&self.coeffs, &rhs.coeffs))
+    }
+}
+
+impl<R, const VAR: Indeterminate> Mul for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R, const VAR: Indeterminate> Neg for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn neg(self) -> Self::Output {
+        DensePolynomial {
+            coeffs: self.coeffs.iter().map(|x| -x).collect()
+        }
+    }
+}
+
+impl<R, const VAR: Indeterminate> Neg for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Sub<&'c DensePolynomial<R, VAR>> for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn sub(self, rhs: &'c DensePolynomial<R, VAR>) -> Self::Output {
+        let mut out = self.clone();
+        out -= rhs;
+        out
+    }
+}
+
+impl<R, const VAR: Indeterminate> Sub for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<R, const VAR: Indeterminate> AddAssign for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<'a, R, const VAR: Indeterminate> AddAssign<&'a DensePolynomial<R, VAR>> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn add_assign(&mut self, rhs: &'a DensePolynomial<R, VAR>) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), R::zero());
+        }
+        for (x, y) in self.coeffs.iter_mut().zip(&rhs.coeffs) {
+            *x += y;
+        }
+        self.prune_zeros();
+    }
+}
+
+impl<R, const VAR: Indeterminate> SubAssign for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<'a, R, const VAR: Indeterminate> SubAssign<&'a DensePolynomial<R, VAR>> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn sub_assign(&mut self, rhs: &'a DensePolynomial<R, VAR>) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), R::zero());
+        }
+        for (x, y) in self.coeffs.iter_mut().zip(&rhs.coeffs) {
+            *x -= y;
+        }
+        self.prune_zeros();
+    }
+}
+
+impl<R, const VAR: Indeterminate> MulAssign for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<'a, R, const VAR: Indeterminate> MulAssign<&'a DensePolynomial<R, VAR>> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn mul_assign(&mut self, rhs: &'a DensePolynomial<R, VAR>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<R, const VAR: Indeterminate> PartialEq for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn eq(&self, other: &Self) -> bool {
+        // Both sides have trailing zeros pruned, so equal polynomials have equal vectors.
+        self.coeffs == other.coeffs
+    }
+}
+
+impl<R, const VAR: Indeterminate> Eq for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'c, 'b> &'c R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{}
+
+impl<R, const VAR: Indeterminate> Ring for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn zero() -> Self {
+        Self {
+            coeffs: Vec::new()
+        }
+    }
+
+    fn one() -> Self {
+        Self::indeterminant_power(0)
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Mul<&'c R> for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn mul(self, rhs: &'c R) -> Self::Output {
+        DensePolynomial::new(self.coeffs.iter().map(|x| x * rhs).collect())
+    }
+}
+
+impl<R, const VAR: Indeterminate> Mul<R> for &DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<R, const VAR: Indeterminate> Mul<R> for DensePolynomial<R, VAR>
+where
+    R: Ring,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Div<&'c R> for &DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn div(self, rhs: &'c R) -> Self::Output {
+        DensePolynomial::new(self.coeffs.iter().map(|x| x / rhs).collect())
+    }
+}
+
+impl<R, const VAR: Indeterminate> Div<R> for &DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn div(self, rhs: R) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl<R, const VAR: Indeterminate> Div<R> for DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = Self;
+
+    fn div(self, rhs: R) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<R, const VAR: Indeterminate> DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// Quotient and remainder in one pass, switching to Newton-iteration division for large degrees.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = division::div_rem(&self.coeffs, &rhs.coeffs);
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// This polynomial scaled to have leading coefficient one. The zero polynomial is returned unchanged.
+    pub fn monic(&self) -> Self {
+        match self.leading() {
+            Some(leading) => self / leading,
+            None => self.clone(),
+        }
+    }
+
+    /// `self^exp mod modulus`, by repeated squaring with a precomputed `Modulus`.
+    pub fn pow_mod(&self, exp: u128, modulus: &Self) -> Self {
+        Modulus::new(modulus).pow_mod(self, exp)
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Div<&'c DensePolynomial<R, VAR>> for &DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn div(self, rhs: &'c DensePolynomial<R, VAR>) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<'c, R, const VAR: Indeterminate> Rem<&'c DensePolynomial<R, VAR>> for &DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = DensePolynomial<R, VAR>;
+
+    fn rem(self, rhs: &'c DensePolynomial<R, VAR>) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<R, const VAR: Indeterminate> Div for DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<R, const VAR: Indeterminate> Rem for DensePolynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,92 @@
+use super::*;
+use crate::{modulo_ints::IMod, X};
+
+type F5 = IMod<5>;
+type F7 = IMod<7>;
+
+fn dense<const P: u64>(coeffs: &[u128]) -> DensePolynomial<IMod<P>, X> {
+    DensePolynomial::new(coeffs.iter().map(|&x| IMod::new(x)).collect())
+}
+
+#[test]
+fn test_dense_construction() {
+    // 3X^2 + 2X + 1, with trailing zeros dropped
+    let p = dense::<5>(&[1, 2, 3, 0, 5]);
+    assert_eq!(p.deg(), Some(2));
+    assert_eq!(p.leading(), Some(&F5::new(3)));
+    assert_eq!(p.coeffs(), &[F5::new(1), F5::new(2), F5::new(3)]);
+    assert_eq!(p.coeff(1), F5::new(2));
+    assert_eq!(p.coeff(10), F5::zero());
+    assert_eq!(dense::<5>(&[0, 0]), DensePolynomial::zero());
+    assert_eq!(DensePolynomial::<F5, X>::zero().deg(), None);
+    assert_eq!(DensePolynomial::<F5, X>::indeterminant_power(3).coeffs(), &[F5::zero(), F5::zero(), F5::zero(), F5::one()]);
+}
+
+#[test]
+fn test_dense_arithmetic() {
+    let p1 = dense::<7>(&[1, 3, 2]);
+    let p2 = dense::<7>(&[2, 4, 5]);
+    // The leading terms cancel, so the degree drops.
+    assert_eq!(&p1 + &p2, dense::<7>(&[3]));
+    assert_eq!(&p1 - &p1, DensePolynomial::zero());
+    assert_eq!(-&p1, dense::<7>(&[6, 4, 5]));
+    // (1 + X)(6 + X) = 6 + 7X + X^2 = 6 + X^2
+    assert_eq!(dense::<7>(&[1, 1]) * dense::<7>(&[6, 1]), dense::<7>(&[6, 0, 1]));
+    assert_eq!(&p1 * F7::new(2), dense::<7>(&[2, 6, 4]));
+    assert_eq!(&p1 / F7::new(2), dense::<7>(&[4, 5, 1]));
+
+    let mut p = p1.clone();
+    p += &p2;
+    p -= p2.clone();
+    assert_eq!(p, p1);
+    p *= DensePolynomial::indeterminant_power(1);
+    assert_eq!(p, dense::<7>(&[0, 1, 3, 2]));
+}
+
+#[test]
+fn test_dense_division() {
+    // 2X^2 + 3X + 1 = (X + 1)(2X + 1) over F5
+    let dividend = dense::<5>(&[1, 3, 2]);
+    let divisor = dense::<5>(&[1, 1]);
+    assert_eq!(&dividend / &divisor, dense::<5>(&[1, 2]));
+    assert_eq!(&dividend % &divisor, DensePolynomial::zero());
+    // X^3 + 2 = (X^2 + 4X + 1)(X + 1) + 1 over F5
+    let dividend = dense::<5>(&[2, 0, 0, 1]);
+    let quotient = &dividend / &divisor;
+    let remainder = &dividend % &divisor;
+    assert_eq!(quotient, dense::<5>(&[1, 4, 1]));
+    assert_eq!(remainder, dense::<5>(&[1]));
+    // Dividing by a polynomial of higher degree leaves everything in the remainder.
+    assert_eq!(&divisor / &dividend, DensePolynomial::zero());
+    assert_eq!(&divisor % &dividend, divisor);
+    assert_eq!(dense::<5>(&[0, 2]).monic(), dense::<5>(&[0, 1]));
+}
+
+#[test]
+fn test_dense_pow_mod() {
+    // Modulo X^2 + 3 we have X^2 = 2, so X^5 = X * 2^2 = 4X
+    let modulus = dense::<5>(&[3, 0, 1]);
+    let x = DensePolynomial::indeterminant_power(1);
+    assert_eq!(x.pow_mod(5, &modulus), dense::<5>(&[0, 4]));
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn test_dense_division_by_zero() {
+    let _result = &dense::<5>(&[1]) / &DensePolynomial::zero();
+}
+
+#[test]
+fn test_dense_matches_sparse() {
+    let a = dense::<7>(&[3, 0, 5, 1, 0, 6]);
+    let b = dense::<7>(&[1, 2, 0, 4]);
+    let (sparse_a, sparse_b) = (Polynomial::from(&a), Polynomial::from(&b));
+    assert_eq!(sparse_a.deg(), Some(5));
+    assert_eq!(DensePolynomial::from(&sparse_a), a);
+    assert_eq!(DensePolynomial::from(&sparse_a * &sparse_b), &a * &b);
+    assert_eq!(DensePolynomial::from(&sparse_a - &sparse_b), &a - &b);
+    assert_eq!(DensePolynomial::from(&sparse_a / &sparse_b), &a / &b);
+    assert_eq!(DensePolynomial::from(&sparse_a % &sparse_b), &a % &b);
+    assert_eq!(Polynomial::from(DensePolynomial::<F7, X>::zero()), Polynomial::zero());
+    assert_eq!(DensePolynomial::from(Polynomial::<F7, X>::indeterminant_power(4)), DensePolynomial::indeterminant_power(4));
+}
@@ -0,0 +1,84 @@
+//! Deterministic text and LaTeX rendering of polynomials.
+//!
+//! Terms are written from the highest degree down, as in `3X^4 + X + 2`. Coefficients equal to one are left out
+//! except on the constant term, and coefficients that are not plain integers (such as `GF` elements like `T + 1`)
+//! are parenthesised, so that the result reads unambiguously and parses back with `str::parse`.
+//!
+//! The parentheses apply to the constant term as well, so a `GF` constant prints as `(2T)` rather than `2T`, and to
+//! coefficients without spaces, as in `(T)X`. Without them neither would parse back, since `2T` reads as `2` times the
+//! variable `T`.
+
+use std::{collections::BTreeMap, fmt::{self, Debug, Display}, ops::{Add, Mul, Neg, Sub}};
+
+use crate::{Indeterminate, Latex, Ring};
+
+use super::Polynomial;
+
+/// Joins the terms of `poly`, highest degree first, rendering coefficients with `coeff` and `VAR^k` with `power`.
+fn render<R: Ring, const VAR: Indeterminate>(poly: &Polynomial<R, VAR>, coeff: impl Fn(&R) -> String, power: impl Fn(usize) -> String) -> String
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let terms: Vec<(usize, &R)> = poly.terms().collect();
+    if terms.is_empty() {
+        return "0".to_string();
+    }
+    let rendered: Vec<String> = terms
+        .into_iter()
+        .rev()
+        .map(|(deg, c)| {
+            if deg > 0 && *c == R::one() {
+                return power(deg);
+            }
+            let c = coeff(c);
+            let c = if c.chars().all(|d| d.is_ascii_digit()) { c } else { format!("({c})") };
+            match deg {
+                0 => c,
+                _ => format!("{c}{}", power(deg)),
+            }
+        })
+        .collect();
+    rendered.join(" + ")
+}
+
+// Display implementation
+impl<R: Ring + Display, const VAR: Indeterminate> Display for Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = render(self, |c| c.to_string(), |deg| match deg {
+            1 => format!("{VAR}"),
+            _ => format!("{VAR}^{deg}"),
+        });
+        f.write_str(&text)
+    }
+}
+
+// Debug implementation
+impl<R: Ring + Debug, const VAR: Indeterminate> Debug for Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    /// Lists the terms in ascending order of degree, so that the output does not depend on hashing.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vals: BTreeMap<usize, &R> = self.terms().collect();
+        f.debug_struct("Polynomial").field("vals", &vals).finish()
+    }
+}
+
+// Latex implementation
+impl<R: Ring + Latex, const VAR: Indeterminate> Latex for Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    fn to_latex(&self) -> String {
+        render(self, Latex::to_latex, |deg| match deg {
+            1 => format!("{VAR}"),
+            _ => format!("{VAR}^{{{deg}}}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,46 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::IMod, T, X, Y};
+
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+
+fn poly<const P: u64, const VAR: Indeterminate>(coeffs: &[u128]) -> Polynomial<IMod<P>, VAR> {
+    Polynomial::from_coeffs(&coeffs.iter().map(|&x| IMod::new(x)).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(poly::<5, X>(&[2, 1, 0, 0, 3]).to_string(), "3X^4 + X + 2");
+    assert_eq!(poly::<5, Y>(&[0, 1]).to_string(), "Y");
+    assert_eq!(poly::<5, X>(&[1]).to_string(), "1");
+    assert_eq!(poly::<5, X>(&[0, 0, 1]).to_string(), "X^2");
+    assert_eq!(Polynomial::<IMod<5>, X>::zero().to_string(), "0");
+}
+
+#[test]
+fn test_display_field_elements() {
+    assert_eq!(IMod::<7>::new(10).to_string(), "3");
+    assert_eq!(F9::zero().to_string(), "0");
+    assert_eq!(F9::new(&[IMod::new(2), IMod::new(1)]).to_string(), "T + 2");
+    assert_eq!(F9::new(&[IMod::new(0), IMod::new(2)]).to_string(), "2T");
+    // Coefficients other than integers are parenthesised.
+    let f = Polynomial::<F9, X>::from_coeffs(&[F9::new(&[IMod::new(0), IMod::new(2)]), F9::new(&[IMod::new(1), IMod::new(1)]), F9::one()]);
+    assert_eq!(f.to_string(), "X^2 + (T + 1)X + (2T)");
+}
+
+#[test]
+fn test_debug_is_ordered() {
+    let f = poly::<7, X>(&[4, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6]);
+    let expected = "Polynomial { vals: {0: IMod { val: 4 }, 2: IMod { val: 1 }, 20: IMod { val: 6 }} }";
+    for _ in 0..8 {
+        assert_eq!(format!("{:?}", f.clone()), expected);
+    }
+}
+
+#[test]
+fn test_latex() {
+    assert_eq!(poly::<5, X>(&[2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3]).to_latex(), "3X^{10} + X + 2");
+    assert_eq!(IMod::<5>::new(4).to_latex(), "4");
+    assert_eq!(F9::new(&[IMod::new(1), IMod::new(1)]).to_latex(), "T + 1");
+    let f = Polynomial::<F9, X>::from_coeffs(&[F9::one(), F9::new(&[IMod::new(1), IMod::new(1)])]);
+    assert_eq!(f.to_latex(), "(T + 1)X + 1");
+}
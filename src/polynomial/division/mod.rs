@@ -0,0 +1,194 @@
+//! Polynomial division with remainder on coefficient slices, lowest power first, and precomputed moduli.
+//!
+//! Small divisions use schoolbook long division in a single pass. Larger ones follow the classical reversal trick:
+//! the quotient of `a` by `b` is read off from the reversed polynomials as `rev(a) / rev(b) mod X^(deg a - deg b + 1)`,
+//! and the power series inverse of `rev(b)` is found by Newton iteration, so division costs a few multiplications.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Field, Indeterminate, Ring};
+
+use super::dense::DensePolynomial;
+
+/// Below this length of quotient or divisor, `div_rem` uses long division instead of Newton iteration.
+pub const NEWTON_THRESHOLD: usize = 128;
+
+/// Quotient and remainder of `a` by `b`, where `b` has a non-zero leading coefficient. Neither output is pruned of
+/// trailing zeros.
+pub fn div_rem<R: Field>(a: &[R], b: &[R]) -> (Vec<R>, Vec<R>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    assert!(!b.is_empty(), "Hey, stop trying to divide by zero!");
+    if a.len() < b.len() {
+        return (Vec::new(), a.to_vec());
+    }
+    if (a.len() - b.len() + 1).min(b.len()) < NEWTON_THRESHOLD {
+        long_division(a, b)
+    } else {
+        newton_division(a, b)
+    }
+}
+
+/// Schoolbook long division in one pass over a copy of `a`, touching only the non-zero coefficients of `b`.
+pub fn long_division<R: Field>(a: &[R], b: &[R]) -> (Vec<R>, Vec<R>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let (b_leading, b_rest) = b.split_last().expect("Hey, stop trying to divide by zero!");
+    if a.len() < b.len() {
+        return (Vec::new(), a.to_vec());
+    }
+    let b_terms: Vec<(usize, &R)> = b_rest.iter().enumerate().filter(|(_, x)| **x != R::zero()).collect();
+    let b_deg = b_rest.len();
+    let mut remainder = a.to_vec();
+    let mut quotient = vec![R::zero(); a.len() - b_deg];
+    for shift in (0..quotient.len()).rev() {
+        let factor = &remainder[shift + b_deg] / b_leading;
+        for &(i, x) in &b_terms {
+            remainder[shift + i] -= &factor * x;
+        }
+        quotient[shift] = factor;
+    }
+    remainder.truncate(b_deg);
+    (quotient, remainder)
+}
+
+/// The first `n` coefficients of the power series inverse of `f`, whose constant term must be a unit.
+///
+/// Newton's iteration `g <- g (2 - f g)` doubles the number of correct coefficients each step, so this costs a
+/// constant number of multiplications of length `n`.
+pub fn reciprocal<R: Field>(f: &[R], n: usize) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if n == 0 {
+        return Vec::new();
+    }
+    let constant = f.first().filter(|x| **x != R::zero()).expect("The constant term of a power series to invert must be a unit");
+    let mut g = vec![&R::one() / constant];
+    let mut len = 1;
+    while len < n {
+        len = (2 * len).min(n);
+        let mut error: Vec<R> = R::multiply_coefficients(&f[..len.min(f.len())], &g).iter().take(len).map(|x| -x).collect();
+        error[0] += &(&R::one() + &R::one());
+        g = R::multiply_coefficients(&g, &error);
+        g.resize(len, R::zero());
+    }
+    g
+}
+
+/// `a - q b` restricted to the coefficients below the degree of `b`, which is the remainder when `q` is the quotient.
+fn remainder_from_quotient<R: Field>(a: &[R], b: &[R], q: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let b_deg = b.len() - 1;
+    let product = R::multiply_coefficients(q, b);
+    let mut remainder = a[..b_deg.min(a.len())].to_vec();
+    for (x, y) in remainder.iter_mut().zip(&product) {
+        *x -= y;
+    }
+    remainder
+}
+
+/// The quotient from the reversed dividend and a power series inverse of the reversed divisor with at least
+/// `quotient_len` terms.
+fn quotient_from_reciprocal<R: Field>(a: &[R], inverse: &[R], quotient_len: usize) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let reversed: Vec<R> = a.iter().rev().take(quotient_len).cloned().collect();
+    let mut quotient = R::multiply_coefficients(&reversed, &inverse[..quotient_len]);
+    quotient.resize(quotient_len, R::zero());
+    quotient.reverse();
+    quotient
+}
+
+/// Division in multiplication time, through a Newton-iteration reciprocal of the reversed divisor.
+pub fn newton_division<R: Field>(a: &[R], b: &[R]) -> (Vec<R>, Vec<R>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    assert!(!b.is_empty(), "Hey, stop trying to divide by zero!");
+    if a.len() < b.len() {
+        return (Vec::new(), a.to_vec());
+    }
+    let quotient_len = a.len() - b.len() + 1;
+    let reversed_divisor: Vec<R> = b.iter().rev().take(quotient_len).cloned().collect();
+    let inverse = reciprocal(&reversed_divisor, quotient_len);
+    let quotient = quotient_from_reciprocal(a, &inverse, quotient_len);
+    let remainder = remainder_from_quotient(a, b, &quotient);
+    (quotient, remainder)
+}
+
+/// A polynomial prepared for repeated reduction, such as the modulus of an extension field.
+///
+/// Stores the power series inverse of the reversed modulus, so reducing a product of two reduced polynomials takes
+/// two multiplications and no division.
+#[derive(Debug, Clone)]
+pub struct Modulus<R: Field, const VAR: Indeterminate>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    modulus: DensePolynomial<R, VAR>,
+    /// `deg(modulus)` terms of the power series inverse of `rev(modulus)`.
+    reciprocal: Vec<R>,
+}
+
+impl<R: Field, const VAR: Indeterminate> Modulus<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    pub fn new(modulus: &DensePolynomial<R, VAR>) -> Self {
+        let deg = modulus.deg().expect("Hey, stop trying to divide by zero!");
+        let reversed: Vec<R> = modulus.coeffs().iter().rev().cloned().collect();
+        Self {
+            modulus: modulus.clone(),
+            reciprocal: reciprocal(&reversed, deg),
+        }
+    }
+
+    pub fn modulus(&self) -> &DensePolynomial<R, VAR> {
+        &self.modulus
+    }
+
+    /// `poly mod modulus`. Inputs of degree below `2 deg(modulus)` use the stored reciprocal, and longer ones fall
+    /// back to `div_rem`.
+    pub fn reduce(&self, poly: &DensePolynomial<R, VAR>) -> DensePolynomial<R, VAR> {
+        let (a, b) = (poly.coeffs(), self.modulus.coeffs());
+        if a.len() < b.len() {
+            return poly.clone();
+        }
+        let quotient_len = a.len() - b.len() + 1;
+        if quotient_len > self.reciprocal.len() {
+            return DensePolynomial::new(div_rem(a, b).1);
+        }
+        let quotient = quotient_from_reciprocal(a, &self.reciprocal, quotient_len);
+        DensePolynomial::new(remainder_from_quotient(a, b, &quotient))
+    }
+
+    /// `a b mod modulus`.
+    pub fn mul_mod(&self, a: &DensePolynomial<R, VAR>, b: &DensePolynomial<R, VAR>) -> DensePolynomial<R, VAR> {
+        self.reduce(&(a * b))
+    }
+
+    /// `base^exp mod modulus`, by repeated squaring.
+    pub fn pow_mod(&self, base: &DensePolynomial<R, VAR>, mut exp: u128) -> DensePolynomial<R, VAR> {
+        let mut result = self.reduce(&DensePolynomial::one());
+        let mut base = self.reduce(base);
+        while exp > 0 {
+            if exp % 2 == 1 {
+                result = self.mul_mod(&result, &base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = self.mul_mod(&base, &base);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,86 @@
+use super::*;
+use crate::{modulo_ints::IMod, polynomial::{multiplication::schoolbook, test_utils::{random_coeffs as random_poly, NTT_PRIME}, Polynomial}, X};
+
+/// Checks `a = q b + r` with `deg r < deg b`.
+fn check_division<const P: u64>(a: &[IMod<P>], b: &[IMod<P>], (q, r): (Vec<IMod<P>>, Vec<IMod<P>>)) {
+    assert!(r.len() < b.len());
+    let mut reconstructed = schoolbook(&q, b);
+    reconstructed.resize(a.len().max(reconstructed.len()), IMod::zero());
+    for (x, y) in reconstructed.iter_mut().zip(&r) {
+        *x += y;
+    }
+    reconstructed.truncate(a.len());
+    assert_eq!(reconstructed, a);
+}
+
+#[test]
+fn test_long_and_newton_division_agree() {
+    for (i, &(m, n)) in [(5, 3), (10, 10), (3, 7), (300, 150), (400, 129), (1000, 20), (1000, 990)].iter().enumerate() {
+        let a = random_poly::<NTT_PRIME>(m, i as u64);
+        let b = random_poly::<NTT_PRIME>(n, 50 + i as u64);
+        let long = long_division(&a, &b);
+        check_division(&a, &b, long.clone());
+        assert_eq!(newton_division(&a, &b), long, "lengths {m} and {n}");
+        assert_eq!(div_rem(&a, &b), long);
+    }
+    // Small characteristic, where multiplication falls back to Karatsuba.
+    let a = random_poly::<2>(600, 1);
+    let b = random_poly::<2>(250, 2);
+    assert_eq!(newton_division(&a, &b), long_division(&a, &b));
+}
+
+#[test]
+fn test_reciprocal() {
+    // 1 / (1 - X) = 1 + X + X^2 + ...
+    let f = [IMod::<7>::one(), -IMod::one()];
+    assert_eq!(reciprocal(&f, 5), vec![IMod::one(); 5]);
+    let f = random_poly::<NTT_PRIME>(300, 3);
+    let inverse = reciprocal(&f, 257);
+    let product = schoolbook(&f, &inverse);
+    assert_eq!(product[0], IMod::one());
+    assert!(product[1..257].iter().all(|x| *x == IMod::zero()));
+}
+
+#[test]
+fn test_modulus_reduce() {
+    let f = DensePolynomial::<IMod<NTT_PRIME>, X>::from_coeffs(&random_poly(200, 4));
+    let modulus = Modulus::new(&f);
+    assert_eq!(modulus.modulus(), &f);
+    for (i, len) in [0, 1, 150, 200, 399, 1000].into_iter().enumerate() {
+        let a = DensePolynomial::from_coeffs(&random_poly(len, 10 + i as u64));
+        assert_eq!(modulus.reduce(&a), &a % &f, "length {len}");
+    }
+    let a = DensePolynomial::from_coeffs(&random_poly(199, 20));
+    let b = DensePolynomial::from_coeffs(&random_poly(150, 21));
+    assert_eq!(modulus.mul_mod(&a, &b), &(&a * &b) % &f);
+    // pow_mod agrees with repeated multiplication.
+    let mut power = DensePolynomial::one();
+    for _ in 0..10 {
+        power = modulus.mul_mod(&power, &a);
+    }
+    assert_eq!(modulus.pow_mod(&a, 10), power);
+    // A constant modulus reduces everything to zero.
+    let constant = Modulus::new(&DensePolynomial::<IMod<5>, X>::from_coeffs(&[IMod::new(3)]));
+    assert_eq!(constant.reduce(&DensePolynomial::indeterminant_power(4)), DensePolynomial::zero());
+}
+
+#[test]
+fn test_polynomial_div_rem() {
+    // X^3 + 2 = (X^2 + 4X + 1)(X + 1) + 1 over F5
+    let a: Polynomial<IMod<5>, X> = Polynomial::from_coeffs(&[IMod::new(2), IMod::zero(), IMod::zero(), IMod::one()]);
+    let b: Polynomial<IMod<5>, X> = Polynomial::from_coeffs(&[IMod::one(), IMod::one()]);
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(q, Polynomial::from_coeffs(&[IMod::one(), IMod::new(4), IMod::one()]));
+    assert_eq!(r, Polynomial::one());
+    assert_eq!(&a / &b, q);
+    assert_eq!(&a % &b, r);
+    let (q, r) = b.div_rem(&a);
+    assert_eq!(q, Polynomial::zero());
+    assert_eq!(r, b);
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn test_modulus_zero() {
+    let _modulus = Modulus::new(&DensePolynomial::<IMod<5>, X>::zero());
+}
@@ -0,0 +1,282 @@
+//! Evaluation of polynomials at points, and interpolation of polynomials from their values.
+//!
+//! Single points use Horner's rule. Batches of points use the subproduct tree, whose nodes are the products of
+//! `X - x_i` over dyadic ranges of points: reducing `f` down the tree leaves `f(x_i)` at the leaves, and combining
+//! scaled products up the tree rebuilds the interpolating polynomial, both in `O(log n)` multiplications of size `n`.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Field, Indeterminate, Ring};
+
+use super::{dense::DensePolynomial, division::Modulus, Polynomial};
+
+/// Below this many points, multipoint evaluation uses Horner's rule at each point and fast interpolation uses
+/// Newton's divided differences.
+pub const SUBPRODUCT_THRESHOLD: usize = 32;
+
+/// `x^exp`, by repeated squaring.
+fn power<S: Ring>(x: &S, mut exp: usize) -> S
+where
+    for<'a, 'b> &'a S : Add<&'b S, Output = S> + Mul<&'b S, Output = S> + Sub<&'b S, Output = S> + Neg<Output = S>
+{
+    let mut result = S::one();
+    let mut base = x.clone();
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result *= &base;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = &base * &base;
+        }
+    }
+    result
+}
+
+/// Horner's rule on `(degree, coefficient)` terms in decreasing order of degree, jumping over runs of zero
+/// coefficients with a power of `x`.
+fn horner<'t, R: Ring + 't, S: Ring>(terms: impl Iterator<Item = (usize, &'t R)>, x: &S, map: impl Fn(&R) -> S) -> S
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>,
+    for<'a, 'b> &'a S : Add<&'b S, Output = S> + Mul<&'b S, Output = S> + Sub<&'b S, Output = S> + Neg<Output = S>
+{
+    let mut result = S::zero();
+    let mut last = 0;
+    for (deg, coeff) in terms {
+        if result != S::zero() {
+            result *= if last - deg == 1 { x.clone() } else { power(x, last - deg) };
+        }
+        result += map(coeff);
+        last = deg;
+    }
+    if last > 0 {
+        result *= power(x, last);
+    }
+    result
+}
+
+/// The subproduct tree over `points`. Level 0 holds the `X - x_i`, and node `i` of each further level is the
+/// product of nodes `2i` and `2i + 1` of the level below, or a copy of node `2i` if that is the last.
+pub fn subproduct_tree<R: Field, const VAR: Indeterminate>(points: &[R]) -> Vec<Vec<DensePolynomial<R, VAR>>>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let mut levels = vec![points.iter().map(|x| DensePolynomial::new(vec![-x, R::one()])).collect::<Vec<_>>()];
+    while levels.last().is_some_and(|level| level.len() > 1) {
+        let next = levels.last().into_iter().flat_map(|level| level.chunks(2)).map(|pair| match pair {
+            [left, right] => left * right,
+            [single] => single.clone(),
+            _ => unreachable!(),
+        });
+        levels.push(next.collect());
+    }
+    levels
+}
+
+/// Writes `f(x_i)` for the points under node `index` of `level` into `out`, where `f` is already reduced modulo that
+/// node.
+fn evaluate_down<R: Field, const VAR: Indeterminate>(tree: &[Vec<DensePolynomial<R, VAR>>], level: usize, index: usize, f: &DensePolynomial<R, VAR>, out: &mut [R])
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if level == 0 {
+        out[index] = f.coeff(0);
+        return;
+    }
+    for child in [2 * index, 2 * index + 1] {
+        if let Some(node) = tree[level - 1].get(child) {
+            evaluate_down(tree, level - 1, child, &(f % node), out);
+        }
+    }
+}
+
+/// The values of `f` at each point of a subproduct tree.
+fn evaluate_with_tree<R: Field, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, tree: &[Vec<DensePolynomial<R, VAR>>]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let mut out = vec![R::zero(); tree[0].len()];
+    let top = tree.len() - 1;
+    evaluate_down(tree, top, 0, &(f % &tree[top][0]), &mut out);
+    out
+}
+
+/// The values of `f` at every point, by Horner's rule for small batches and the subproduct tree otherwise.
+pub fn multipoint_evaluate<R: Field, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, points: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if points.len() < SUBPRODUCT_THRESHOLD {
+        return points.iter().map(|x| f.eval(x)).collect();
+    }
+    evaluate_with_tree(f, &subproduct_tree(points))
+}
+
+/// `y / d`, panicking with a message about repeated points when `d` is zero.
+fn divide_distinct<R: Field>(y: &R, d: &R) -> R
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    assert!(*d != R::zero(), "Interpolation points must have distinct x values");
+    y / d
+}
+
+/// The interpolating polynomial of degree below `points.len()` through the `(x, y)` pairs, as the sum of the
+/// Lagrange basis polynomials `y_i prod_{j != i} (X - x_j) / (x_i - x_j)`, in `O(n^2)` operations.
+pub fn lagrange<R: Field, const VAR: Indeterminate>(points: &[(R, R)]) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let product = points.iter().fold(DensePolynomial::one(), |product, (x, _)| &product * &DensePolynomial::new(vec![-x, R::one()]));
+    let mut out = DensePolynomial::zero();
+    for (x, y) in points {
+        let (basis, _) = product.div_rem(&DensePolynomial::new(vec![-x, R::one()]));
+        out += &(&basis * &divide_distinct(y, &basis.eval(x)));
+    }
+    out
+}
+
+/// The interpolating polynomial through the `(x, y)` pairs in Newton form, from the table of divided differences,
+/// in `O(n^2)` operations.
+pub fn newton<R: Field, const VAR: Indeterminate>(points: &[(R, R)]) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    // After step `k`, differences[i] = f[x_(i-k), ..., x_i] for i >= k.
+    let mut differences: Vec<R> = points.iter().map(|(_, y)| y.clone()).collect();
+    for k in 1..points.len() {
+        for i in (k..points.len()).rev() {
+            let numerator = &differences[i] - &differences[i - 1];
+            differences[i] = divide_distinct(&numerator, &(&points[i].0 - &points[i - k].0));
+        }
+    }
+    // c_0 + (X - x_0)(c_1 + (X - x_1)(c_2 + ...)), from the inside out.
+    let mut out = DensePolynomial::zero();
+    for ((x, _), c) in points.iter().zip(&differences).rev() {
+        out = &(&out * &DensePolynomial::new(vec![-x, R::one()])) + &DensePolynomial::new(vec![c.clone()]);
+    }
+    out
+}
+
+/// The sum over the points under node `index` of `level` of `weights[i]` times the product of the `X - x_j` over the
+/// other points under the node.
+fn combine_up<R: Field, const VAR: Indeterminate>(tree: &[Vec<DensePolynomial<R, VAR>>], level: usize, index: usize, weights: &[R]) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if level == 0 {
+        return DensePolynomial::new(vec![weights[index].clone()]);
+    }
+    let left = combine_up(tree, level - 1, 2 * index, weights);
+    match tree[level - 1].get(2 * index + 1) {
+        Some(right_node) => {
+            let right = combine_up(tree, level - 1, 2 * index + 1, weights);
+            &(&left * right_node) + &(&right * &tree[level - 1][2 * index])
+        }
+        None => left,
+    }
+}
+
+/// The interpolating polynomial through the `(x, y)` pairs in `O(log n)` multiplications of size `n` for large
+/// inputs, and by Newton's divided differences for small ones.
+///
+/// With `M = prod (X - x_i)`, the interpolant is `sum y_i / M'(x_i) * M / (X - x_i)`. The `M'(x_i)` come from
+/// multipoint evaluation on the subproduct tree, and the sum is formed up the same tree.
+pub fn fast_interpolate<R: Field, const VAR: Indeterminate>(points: &[(R, R)]) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if points.len() < SUBPRODUCT_THRESHOLD {
+        return newton(points);
+    }
+    let xs: Vec<R> = points.iter().map(|(x, _)| x.clone()).collect();
+    let tree = subproduct_tree(&xs);
+    let product = &tree[tree.len() - 1][0];
+    let weights: Vec<R> = evaluate_with_tree(&product.derivative(), &tree).iter().zip(points).map(|(d, (_, y))| divide_distinct(y, d)).collect();
+    combine_up(&tree, tree.len() - 1, 0, &weights)
+}
+
+impl<R: Ring, const VAR: Indeterminate> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    /// The value at `x`, by Horner's rule.
+    pub fn eval(&self, x: &R) -> R {
+        self.eval_in(x)
+    }
+
+    /// The value at `x` in a ring `S` that the coefficients map into, such as an extension field of a prime field.
+    pub fn eval_in<S: Ring + From<R>>(&self, x: &S) -> S
+    where
+        for<'a, 'b> &'a S : Add<&'b S, Output = S> + Mul<&'b S, Output = S> + Sub<&'b S, Output = S> + Neg<Output = S>
+    {
+        horner(self.coeffs().iter().enumerate().rev(), x, |c| S::from(c.clone()))
+    }
+}
+
+impl<R: Ring, const VAR: Indeterminate> Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    /// The value at `x`, by Horner's rule over the non-zero terms.
+    pub fn eval(&self, x: &R) -> R {
+        self.eval_in(x)
+    }
+
+    /// The value at `x` in a ring `S` that the coefficients map into, so that for example a `Polynomial<IMod<P>, _>`
+    /// can be evaluated at an element of `GF<P, N, _>`.
+    pub fn eval_in<S: Ring + From<R>>(&self, x: &S) -> S
+    where
+        for<'a, 'b> &'a S : Add<&'b S, Output = S> + Mul<&'b S, Output = S> + Sub<&'b S, Output = S> + Neg<Output = S>
+    {
+        let terms: Vec<(usize, &R)> = self.terms().collect();
+        horner(terms.into_iter().rev(), x, |c| S::from(c.clone()))
+    }
+
+    /// The composition `self(g)`, by Horner's rule in the polynomial ring.
+    pub fn compose(&self, g: &Self) -> Self {
+        let terms: Vec<(usize, &R)> = self.terms().collect();
+        horner::<R, Self>(terms.into_iter().rev(), g, |c| Self::from_coeffs(std::slice::from_ref(c)))
+    }
+}
+
+impl<R: Field, const VAR: Indeterminate> Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// The modular composition `self(g) mod modulus`, by Horner's rule with every product reduced, so that no
+    /// intermediate result exceeds twice the degree of `modulus`.
+    pub fn compose_mod(&self, g: &Self, modulus: &Self) -> Self {
+        let modulus = Modulus::new(&DensePolynomial::from(modulus));
+        let g = modulus.reduce(&DensePolynomial::from(g));
+        let mut result = DensePolynomial::zero();
+        for c in DensePolynomial::from(self).coeffs().iter().rev() {
+            result = &modulus.mul_mod(&result, &g) + &DensePolynomial::new(vec![c.clone()]);
+        }
+        Self::from(modulus.reduce(&result))
+    }
+
+    /// The values at every point, using the subproduct tree for large batches.
+    pub fn eval_many(&self, points: &[R]) -> Vec<R> {
+        multipoint_evaluate(&DensePolynomial::from(self), points)
+    }
+
+    /// The unique polynomial of degree below `points.len()` through the `(x, y)` pairs, by the fastest available
+    /// method. Panics if two points share an `x`.
+    pub fn interpolate(points: &[(R, R)]) -> Self {
+        Self::from(fast_interpolate(points))
+    }
+
+    /// As `interpolate`, by Lagrange's formula.
+    pub fn interpolate_lagrange(points: &[(R, R)]) -> Self {
+        Self::from(lagrange(points))
+    }
+
+    /// As `interpolate`, by Newton's divided differences.
+    pub fn interpolate_newton(points: &[(R, R)]) -> Self {
+        Self::from(newton(points))
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,106 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::{zmod::ZMod, IMod}, polynomial::test_utils::{random_imod, NTT_PRIME}, T, X};
+
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+
+#[test]
+fn test_eval() {
+    // 3X^5 + X + 2 over F7 at 2 is 96 + 4 = 100 = 2
+    let mut terms = std::collections::HashMap::new();
+    terms.insert(5, IMod::<7>::new(3));
+    terms.insert(1, IMod::one());
+    terms.insert(0, IMod::new(2));
+    let f: Polynomial<IMod<7>, X> = Polynomial::new(&terms);
+    assert_eq!(f.eval(&IMod::new(2)), IMod::new(2));
+    assert_eq!(DensePolynomial::from(&f).eval(&IMod::new(2)), IMod::new(2));
+    assert_eq!(f.eval(&IMod::zero()), IMod::new(2));
+    assert_eq!(Polynomial::<IMod<7>, X>::zero().eval(&IMod::new(3)), IMod::zero());
+    // A large sparse power and a ring that is not a field.
+    let f: Polynomial<ZMod<12>, X> = Polynomial::indeterminant_power(1000);
+    assert_eq!(f.eval(&ZMod::new(5)), ZMod::new(1));
+    assert_eq!(f.eval(&ZMod::new(2)), ZMod::new(4));
+}
+
+#[test]
+fn test_eval_in_extension() {
+    // X^2 + 1 over F3 vanishes exactly at its roots in GF(9).
+    let f: Polynomial<IMod<3>, X> = Polynomial::from_coeffs(&[IMod::one(), IMod::zero(), IMod::one()]);
+    let roots: Vec<F9> = F9::elements().filter(|x| f.eval_in(x) == F9::zero()).collect();
+    assert_eq!(roots, f.roots_in::<F9>().into_iter().map(|(x, _)| x).collect::<Vec<_>>());
+    assert_eq!(roots.len(), 2);
+    for x in F9::elements() {
+        assert_eq!(f.eval_in(&x), x * x + F9::one());
+    }
+}
+
+#[test]
+fn test_multipoint_evaluation() {
+    let f: DensePolynomial<IMod<NTT_PRIME>, X> = DensePolynomial::new(random_imod(300, 1));
+    for (i, n) in [0, 1, 5, 31, 32, 100, 517].into_iter().enumerate() {
+        let points = random_imod(n, 10 + i as u64);
+        let expected: Vec<_> = points.iter().map(|x| f.eval(x)).collect();
+        assert_eq!(multipoint_evaluate(&f, &points), expected, "{n} points");
+    }
+    // Polynomials of lower degree than the number of points, including repeated points.
+    let g: Polynomial<IMod<NTT_PRIME>, X> = Polynomial::from_coeffs(&random_imod(3, 2));
+    let mut points = random_imod(60, 3);
+    points.push(points[0]);
+    assert_eq!(g.eval_many(&points), points.iter().map(|x| g.eval(x)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_interpolation() {
+    for (i, n) in [0, 1, 2, 17, 32, 200].into_iter().enumerate() {
+        let xs = (0..n).map(|x| IMod::<NTT_PRIME>::new(7 * x as u128 + 3));
+        let points: Vec<_> = xs.zip(random_imod(n, 20 + i as u64)).collect();
+        let f = fast_interpolate::<_, X>(&points);
+        assert!(f.deg().is_none_or(|deg| deg < n.max(1)));
+        for (x, y) in &points {
+            assert_eq!(f.eval(x), *y);
+        }
+        assert_eq!(newton(&points), f, "{n} points");
+        if n <= 32 {
+            assert_eq!(lagrange(&points), f);
+        }
+    }
+    // Interpolation recovers a polynomial from enough of its values.
+    let f: Polynomial<IMod<NTT_PRIME>, X> = Polynomial::from_coeffs(&random_imod(50, 4));
+    let points: Vec<_> = random_imod(64, 5).into_iter().map(|x| (x, f.eval(&x))).collect();
+    assert_eq!(Polynomial::interpolate(&points), f);
+    assert_eq!(Polynomial::interpolate_lagrange(&points), f);
+    assert_eq!(Polynomial::interpolate_newton(&points), f);
+    // Over an extension field.
+    let points: Vec<(F9, F9)> = F9::elements().map(|x| (x, x.pow(5))).collect();
+    let f: Polynomial<F9, X> = Polynomial::interpolate(&points);
+    assert_eq!(f, Polynomial::indeterminant_power(5));
+}
+
+#[test]
+fn test_composition() {
+    // (X^2 + 1)(X + 2) = X^2 + 4X + 5 over F7
+    let f: Polynomial<IMod<7>, X> = Polynomial::from_coeffs(&[IMod::one(), IMod::zero(), IMod::one()]);
+    let g: Polynomial<IMod<7>, X> = Polynomial::from_coeffs(&[IMod::new(2), IMod::one()]);
+    assert_eq!(f.compose(&g), Polynomial::from_coeffs(&[IMod::new(5), IMod::new(4), IMod::one()]));
+    assert_eq!(g.compose(&f), Polynomial::from_coeffs(&[IMod::new(3), IMod::zero(), IMod::one()]));
+    assert_eq!(f.compose(&Polynomial::indeterminant_power(1)), f);
+    assert_eq!(f.compose(&Polynomial::zero()), Polynomial::one());
+    // Composition commutes with evaluation.
+    let f: Polynomial<IMod<NTT_PRIME>, X> = Polynomial::from_coeffs(&random_imod(20, 6));
+    let g: Polynomial<IMod<NTT_PRIME>, X> = Polynomial::from_coeffs(&random_imod(7, 7));
+    let fg = f.compose(&g);
+    assert_eq!(fg.deg(), Some(19 * 6));
+    for x in random_imod::<NTT_PRIME>(5, 8) {
+        assert_eq!(fg.eval(&x), f.eval(&g.eval(&x)));
+    }
+    // Modular composition agrees with reducing the full composition.
+    let h: Polynomial<IMod<NTT_PRIME>, X> = Polynomial::from_coeffs(&random_imod(30, 9));
+    assert_eq!(f.compose_mod(&g, &h), &fg % &h);
+    assert_eq!(Polynomial::indeterminant_power(1).compose_mod(&g, &h), &g % &h);
+}
+
+#[test]
+#[should_panic(expected = "distinct x values")]
+fn test_interpolation_repeated_point() {
+    let points = [(IMod::<5>::new(1), IMod::new(2)), (IMod::new(1), IMod::new(3))];
+    Polynomial::<IMod<5>, X>::interpolate(&points);
+}
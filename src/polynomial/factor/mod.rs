@@ -0,0 +1,322 @@
+//! Factorization of polynomials over finite fields into monic irreducibles.
+//!
+//! A polynomial is first split by square-free factorization into pairwise coprime square-free parts, one for each
+//! multiplicity. Each part is then split into irreducibles, either by distinct-degree factorization followed by
+//! Cantor-Zassenhaus equal-degree splitting, or by Berlekamp's algorithm. The pieces that depend on the coefficient
+//! field (p-th roots, random elements, enumerating the field) are passed in by the callers, so that every finite
+//! field shares the same pipeline.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{galois::GF, modulo_ints::IMod, FiniteField, Indeterminate, Ring};
+
+use super::{dense::DensePolynomial, division::Modulus, Polynomial};
+
+/// Deterministic splitmix64 stream for the random choices of Cantor-Zassenhaus, so that factoring is reproducible.
+pub(super) struct SplitMix(pub(super) u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value below `bound`, from two draws so that every `u128` bound is covered.
+    pub(super) fn below(&mut self, bound: u128) -> u128 {
+        (((self.next() as u128) << 64) | self.next() as u128) % bound
+    }
+}
+
+/// `a^q` modulo `modulus` for `q = R::size()`, computed as `a^(q - 1) a` so that `q` itself never has to fit in a
+/// `u128`.
+pub(super) fn pow_size<R: FiniteField, const VAR: Indeterminate>(modulus: &Modulus<R, VAR>, a: &DensePolynomial<R, VAR>) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    modulus.mul_mod(&modulus.pow_mod(a, R::group_order()), a)
+}
+
+/// Square-free factorization of a monic `f` over a field of characteristic `p`, as pairs `(g, m)` of square-free,
+/// pairwise coprime, monic `g` of positive degree with `f` the product of the `g^m`.
+///
+/// Unlike in characteristic zero, `f' = 0` does not make `f` constant: it makes `f` a `p`-th power, whose root is
+/// taken coefficient by coefficient with `pth_root` and factored in turn.
+pub fn square_free<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, p: u128, pth_root: &impl Fn(&R) -> R) -> Vec<(DensePolynomial<R, VAR>, u32)>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let one = DensePolynomial::one();
+    let mut out = Vec::new();
+    let mut c = f.clone();
+    let derivative = f.derivative();
+    if derivative.deg().is_some() {
+        // `w` collects the factors of multiplicity at least `i` not divisible by `p`, and `c` everything else.
+        c = f.gcd(&derivative);
+        let mut w = f / &c;
+        let mut i = 1;
+        while w != one {
+            let y = w.gcd(&c);
+            let factor = &w / &y;
+            if factor != one {
+                out.push((factor, i));
+            }
+            c = &c / &y;
+            w = y;
+            i += 1;
+        }
+    }
+    if c != one {
+        // Every multiplicity left in `c` is a multiple of `p`, and `p <= deg c`.
+        let root = DensePolynomial::new(c.coeffs().iter().step_by(p as usize).map(pth_root).collect());
+        out.extend(square_free(&root, p, pth_root).into_iter().map(|(g, m)| (g, m * p as u32)));
+    }
+    out
+}
+
+/// Distinct-degree factorization of a monic square-free `f`, as pairs `(g, d)` where `g` is the product of the
+/// irreducible factors of `f` of degree `d`.
+///
+/// The irreducibles of degree dividing `d` are exactly the factors of `VAR^(q^d) - VAR`, so each `g` is a GCD with
+/// it once the lower degrees have been divided out.
+pub fn distinct_degree<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>) -> Vec<(DensePolynomial<R, VAR>, usize)>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let x = DensePolynomial::indeterminant_power(1);
+    let mut out = Vec::new();
+    let mut f = f.clone();
+    // h = VAR^(q^d) mod f
+    let mut h = &x % &f;
+    let mut d = 1;
+    while f.deg().is_some_and(|n| n >= 2 * d) {
+        h = pow_size(&Modulus::new(&f), &h);
+        let g = f.gcd(&(&h - &x));
+        if g.deg() != Some(0) {
+            f = &f / &g;
+            h = &h % &f;
+            out.push((g, d));
+        }
+        d += 1;
+    }
+    if let Some(n) = f.deg().filter(|&n| n > 0) {
+        out.push((f, n));
+    }
+    out
+}
+
+/// For `a` modulo a product of irreducibles of degree `d`, a polynomial that is `0` modulo some of them and a unit
+/// modulo the others, each with probability about one half.
+///
+/// For odd `q` this is `a^((q^d - 1) / 2) - 1`, computed as `b^(1 + q + ... + q^(d-1)) - 1` with
+/// `b = a^((q - 1) / 2)` so that no exponent overflows. For `q = 2^k` it is the trace `a + a^2 + ... + a^(2^(kd-1))`
+/// of `a` down to `GF(2)`.
+fn splitting_map<R: FiniteField, const VAR: Indeterminate>(modulus: &Modulus<R, VAR>, a: &DensePolynomial<R, VAR>, d: usize) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let order = R::group_order();
+    if order % 2 == 0 {
+        let mut power = modulus.pow_mod(a, order / 2);
+        let mut product = power.clone();
+        for _ in 1..d {
+            power = pow_size(modulus, &power);
+            product = modulus.mul_mod(&product, &power);
+        }
+        &product - &DensePolynomial::one()
+    } else {
+        let mut power = modulus.reduce(a);
+        let mut trace = power.clone();
+        // q - 1 = 2^k - 1 has k bits set.
+        for _ in 1..order.count_ones() as usize * d {
+            power = modulus.mul_mod(&power, &power);
+            trace += &power;
+        }
+        trace
+    }
+}
+
+/// Cantor-Zassenhaus splitting of a monic square-free `f` whose irreducible factors all have degree `d`, drawing
+/// coefficients of the random polynomials from `random`.
+pub fn equal_degree<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, d: usize, random: &mut impl FnMut() -> R) -> Vec<DensePolynomial<R, VAR>>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let n = f.deg().expect("Cannot split the zero polynomial");
+    if n <= d {
+        return vec![f.clone()];
+    }
+    let modulus = Modulus::new(f);
+    loop {
+        let a = DensePolynomial::new((0..n).map(|_| random()).collect());
+        let g = f.gcd(&splitting_map(&modulus, &a, d));
+        if g.deg().is_some_and(|k| 0 < k && k < n) {
+            let mut out = equal_degree(&g, d, random);
+            out.extend(equal_degree(&(f / &g), d, random));
+            return out;
+        }
+    }
+}
+
+/// A basis of the solutions `v` of `v^q = v` modulo `f`, as coefficient vectors below `deg f`.
+///
+/// Since `v^q = sum v_i VAR^(qi)`, these are the left kernel of `Q - I` where row `i` of `Q` is `VAR^(qi) mod f`.
+fn berlekamp_basis<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>) -> Vec<DensePolynomial<R, VAR>>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let n = f.deg().expect("Cannot factor the zero polynomial");
+    let modulus = Modulus::new(f);
+    let x_q = pow_size(&modulus, &DensePolynomial::indeterminant_power(1));
+    // columns[j][i] is entry (i, j) of Q - I, so the kernel of `columns` as a matrix is the left kernel of Q - I.
+    let mut columns = vec![vec![R::zero(); n]; n];
+    let mut row = modulus.reduce(&DensePolynomial::one());
+    for i in 0..n {
+        for (j, column) in columns.iter_mut().enumerate() {
+            column[i] = row.coeff(j);
+        }
+        columns[i][i] -= R::one();
+        row = modulus.mul_mod(&row, &x_q);
+    }
+    // Reduced row echelon form, remembering the pivot column of each row.
+    let mut pivots = Vec::new();
+    for col in 0..n {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..n).find(|&r| columns[r][col] != R::zero()) else {
+            continue;
+        };
+        columns.swap(rank, pivot);
+        let pivot_value = columns[rank][col];
+        for x in columns[rank].iter_mut() {
+            *x /= &pivot_value;
+        }
+        let pivot_row = columns[rank].clone();
+        for (r, row) in columns.iter_mut().enumerate() {
+            let factor = row[col];
+            if r != rank && factor != R::zero() {
+                for (x, &y) in row.iter_mut().zip(&pivot_row) {
+                    let mut delta = y;
+                    delta *= &factor;
+                    *x -= &delta;
+                }
+            }
+        }
+        pivots.push(col);
+    }
+    // One basis vector per free column, with the pivot variables solved for.
+    (0..n)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut v = vec![R::zero(); n];
+            v[free] = R::one();
+            for (r, &pivot) in pivots.iter().enumerate() {
+                v[pivot] = -&columns[r][free];
+            }
+            DensePolynomial::new(v)
+        })
+        .collect()
+}
+
+/// Berlekamp's factorization of a monic square-free `f` into irreducibles.
+///
+/// Every `v` with `v^q = v` modulo `f` is a constant modulo each irreducible factor, so `f` is the product of the
+/// `gcd(f, v - c)` over all `c` in the field, which `elements` must enumerate. The number of irreducible factors is
+/// the dimension of the space of such `v`, so this is deterministic but costs a GCD per field element.
+pub fn berlekamp<R: FiniteField, const VAR: Indeterminate, I: Iterator<Item = R>>(f: &DensePolynomial<R, VAR>, elements: &impl Fn() -> I) -> Vec<DensePolynomial<R, VAR>>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let basis = berlekamp_basis(f);
+    let mut factors = vec![f.clone()];
+    for v in basis.iter().filter(|v| v.deg().is_some_and(|deg| deg > 0)) {
+        if factors.len() == basis.len() {
+            break;
+        }
+        factors = factors
+            .iter()
+            .flat_map(|u| {
+                if u.deg() == Some(1) {
+                    return vec![u.clone()];
+                }
+                elements()
+                    .map(|c| u.gcd(&(v - &DensePolynomial::new(vec![c]))))
+                    .filter(|g| g.deg().is_some_and(|deg| deg > 0))
+                    .collect()
+            })
+            .collect();
+    }
+    factors
+}
+
+/// The leading coefficient of `f` and its monic irreducible factors with multiplicities, splitting each square-free
+/// part with `split` and sorting by degree and then by `index` of the coefficients, lowest power first.
+fn factor_with<R: FiniteField, const VAR: Indeterminate>(
+    f: &Polynomial<R, VAR>,
+    p: u128,
+    pth_root: &impl Fn(&R) -> R,
+    mut split: impl FnMut(&DensePolynomial<R, VAR>) -> Vec<DensePolynomial<R, VAR>>,
+    index: impl Fn(&R) -> u128,
+) -> (R, Vec<(Polynomial<R, VAR>, u32)>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let leading = *f.leading().expect("Cannot factor the zero polynomial");
+    let monic = DensePolynomial::from(f).monic();
+    let mut factors: Vec<(DensePolynomial<R, VAR>, u32)> = square_free(&monic, p, pth_root)
+        .into_iter()
+        .flat_map(|(part, m)| split(&part).into_iter().map(move |g| (g, m)))
+        .collect();
+    factors.sort_by_cached_key(|(g, _)| (g.deg(), g.coeffs().iter().map(&index).collect::<Vec<_>>()));
+    (leading, factors.into_iter().map(|(g, m)| (Polynomial::from(g), m)).collect())
+}
+
+impl<const P: u64, const VAR: Indeterminate> Polynomial<IMod<P>, VAR> {
+    /// The leading coefficient and the monic irreducible factors with their multiplicities, sorted by degree and then
+    /// by coefficients, by square-free, distinct-degree and Cantor-Zassenhaus equal-degree factorization. Constants
+    /// have no factors.
+    ///
+    /// The random choices come from a fixed seed, so the result is reproducible. Panics on the zero polynomial.
+    pub fn factor(&self) -> (IMod<P>, Vec<(Self, u32)>) {
+        let mut rng = SplitMix(P);
+        let mut random = || IMod::new(rng.below(P as u128));
+        factor_with(self, P as u128, &|x| *x, |part| {
+            distinct_degree(part).into_iter().flat_map(|(g, d)| equal_degree(&g, d, &mut random)).collect()
+        }, |x| x.value())
+    }
+
+    /// As `factor`, but splitting the square-free parts with Berlekamp's deterministic algorithm, which tries every
+    /// element of `IMod<P>` and so is only practical for small `P`.
+    pub fn factor_berlekamp(&self) -> (IMod<P>, Vec<(Self, u32)>) {
+        factor_with(self, P as u128, &|x| *x, |part| berlekamp(part, &|| (0..P as u128).map(IMod::new)), |x| x.value())
+    }
+}
+
+impl<const P: u64, const N: usize, const SYMBOL: Indeterminate, const MODULUS: &'static [u64], const VAR: Indeterminate> Polynomial<GF<P, N, SYMBOL, MODULUS>, VAR> {
+    /// The leading coefficient and the monic irreducible factors with their multiplicities, sorted by degree and then
+    /// by the `index` of the coefficients, by square-free, distinct-degree and Cantor-Zassenhaus equal-degree
+    /// factorization. Constants have no factors.
+    ///
+    /// `P`-th roots in the square-free step use `GF::pth_root`, and in characteristic 2 equal-degree splitting uses
+    /// the trace down to `GF(2)`. The random choices come from a fixed seed, so the result is reproducible. Panics on
+    /// the zero polynomial.
+    pub fn factor(&self) -> (GF<P, N, SYMBOL, MODULUS>, Vec<(Self, u32)>) {
+        // The random coefficients are drawn from the non-zero elements, whose number fits in a `u128` for every field.
+        let order = GF::<P, N, SYMBOL, MODULUS>::group_order();
+        let mut rng = SplitMix(order as u64);
+        let mut random = || GF::from_index(rng.below(order) + 1);
+        factor_with(self, P as u128, &GF::pth_root, |part| {
+            distinct_degree(part).into_iter().flat_map(|(g, d)| equal_degree(&g, d, &mut random)).collect()
+        }, GF::index)
+    }
+
+    /// As `factor`, but splitting the square-free parts with Berlekamp's deterministic algorithm, which tries every
+    /// element of the field and so is only practical for small fields.
+    pub fn factor_berlekamp(&self) -> (GF<P, N, SYMBOL, MODULUS>, Vec<(Self, u32)>) {
+        factor_with(self, P as u128, &GF::pth_root, |part| berlekamp(part, &GF::elements), GF::index)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,154 @@
+use super::*;
+use crate::{T, X};
+
+type Poly<const P: u64> = Polynomial<IMod<P>, X>;
+
+fn poly<const P: u64>(coeffs: &[u128]) -> Poly<P> {
+    Poly::from_coeffs(&coeffs.iter().map(|&x| IMod::new(x)).collect::<Vec<_>>())
+}
+
+fn power<const P: u64>(g: &Poly<P>, m: u32) -> Poly<P> {
+    (0..m).fold(Poly::one(), |product, _| &product * g)
+}
+
+/// Multiplies a factorization back out.
+fn expand<const P: u64>((leading, factors): &(IMod<P>, Vec<(Poly<P>, u32)>)) -> Poly<P> {
+    factors.iter().fold(Poly::one() * *leading, |product, (g, m)| &product * &power(g, *m))
+}
+
+/// Checks that both algorithms give the same valid factorization of `f`, and returns it.
+fn check_factor<const P: u64>(f: &Poly<P>) -> (IMod<P>, Vec<(Poly<P>, u32)>) {
+    let factorization = f.factor();
+    assert_eq!(&expand(&factorization), f);
+    for (g, _) in &factorization.1 {
+        assert!(g.is_irreducible(), "{g:?} is reducible");
+        assert_eq!(g.leading(), Some(&IMod::one()));
+    }
+    assert_eq!(f.factor_berlekamp(), factorization);
+    factorization
+}
+
+#[test]
+fn test_square_free() {
+    // (X + 1)^4 (X^2 + 1)^3 X over F3, where the cube is only visible through a p-th root.
+    let f = &(&power(&poly::<3>(&[1, 1]), 4) * &power(&poly::<3>(&[1, 0, 1]), 3)) * &poly::<3>(&[0, 1]);
+    let parts = square_free(&DensePolynomial::from(&f), 3, &|x| *x);
+    let mut parts: Vec<(Poly<3>, u32)> = parts.into_iter().map(|(g, m)| (Polynomial::from(g), m)).collect();
+    parts.sort_by_key(|(_, m)| *m);
+    assert_eq!(parts, vec![(poly(&[0, 1]), 1), (poly(&[1, 0, 1]), 3), (poly(&[1, 1]), 4)]);
+    // A pure p-th power has zero derivative.
+    let f = power(&poly::<2>(&[1, 1, 1]), 8);
+    let parts = square_free(&DensePolynomial::from(&f), 2, &|x| *x);
+    assert_eq!(parts, vec![(DensePolynomial::from(&poly::<2>(&[1, 1, 1])), 8)]);
+}
+
+#[test]
+fn test_distinct_degree() {
+    // X^9 - X over F3 is the product of all monic irreducibles of degree 1 and 2.
+    let f = DensePolynomial::from(&(&Poly::<3>::indeterminant_power(9) - &Poly::indeterminant_power(1)));
+    let parts = distinct_degree(&f);
+    assert_eq!(parts.iter().map(|(g, d)| (g.deg(), *d)).collect::<Vec<_>>(), vec![(Some(3), 1), (Some(6), 2)]);
+}
+
+#[test]
+fn test_factor_small() {
+    // X^4 + 1 = (X^2 + X + 2)(X^2 + 2X + 2) over F3
+    assert_eq!(check_factor(&poly::<3>(&[1, 0, 0, 0, 1])), (IMod::one(), vec![(poly(&[2, 1, 1]), 1), (poly(&[2, 2, 1]), 1)]));
+    // X^15 - 1 over F2 has the factors of the cyclotomic polynomials of orders 1, 3, 5 and 15.
+    let f = &Poly::<2>::indeterminant_power(15) - &Poly::one();
+    let (_, factors) = check_factor(&f);
+    assert_eq!(factors.iter().map(|(g, m)| (g.deg(), *m)).collect::<Vec<_>>(), vec![(Some(1), 1), (Some(2), 1), (Some(4), 1), (Some(4), 1), (Some(4), 1)]);
+    // The leading coefficient is split off.
+    let (leading, factors) = check_factor(&(&power(&poly::<7>(&[1, 1]), 2) * IMod::new(3)));
+    assert_eq!(leading, IMod::new(3));
+    assert_eq!(factors, vec![(poly(&[1, 1]), 2)]);
+    // Constants have no factors.
+    assert_eq!(check_factor(&poly::<7>(&[5])), (IMod::new(5), vec![]));
+}
+
+#[test]
+fn test_factor_with_multiplicities() {
+    // Factors of several degrees over F2 and F3, with multiplicities that are and are not multiples of p.
+    let f = &(&(&power(&poly::<2>(&[1, 1, 0, 1]), 2) * &power(&poly::<2>(&[1, 1, 1]), 4)) * &power(&poly::<2>(&[0, 1]), 3)) * &poly::<2>(&[1, 0, 0, 1, 1]);
+    let (_, factors) = check_factor(&f);
+    assert_eq!(factors, vec![(poly(&[0, 1]), 3), (poly(&[1, 1, 1]), 4), (poly(&[1, 1, 0, 1]), 2), (poly(&[1, 0, 0, 1, 1]), 1)]);
+    let f = &(&power(&poly::<3>(&[1, 2, 0, 1]), 6) * &power(&poly::<3>(&[1, 0, 1]), 5)) * &poly::<3>(&[1, 2, 0, 0, 0, 1]);
+    let (_, factors) = check_factor(&f);
+    assert_eq!(factors.iter().map(|(g, m)| (g.deg(), *m)).collect::<Vec<_>>(), vec![(Some(2), 5), (Some(3), 6), (Some(5), 1)]);
+}
+
+#[test]
+fn test_factor_large_prime() {
+    // Several irreducible factors of the same degree force Cantor-Zassenhaus to split repeatedly.
+    const P: u64 = 998_244_353;
+    let quadratics: Vec<Poly<P>> = (2..).map(|c| poly(&[c, 0, 1])).filter(Poly::is_irreducible).take(4).collect();
+    let linear: Vec<Poly<P>> = (1..4).map(|c| poly(&[c, 1])).collect();
+    let f = quadratics.iter().chain(&linear).fold(Poly::<P>::one(), |product, g| &product * g);
+    let f = &f * &linear[0];
+    let (leading, factors) = f.factor();
+    assert_eq!(leading, IMod::one());
+    assert_eq!(expand(&(leading, factors.clone())), f);
+    assert_eq!(factors.len(), 7);
+    assert_eq!(factors.iter().filter(|(_, m)| *m == 2).count(), 1);
+}
+
+type F4 = GF<2, 2, T, {&[1, 1, 1]}>;
+type F8 = GF<2, 3, T, {&[1, 1, 0, 1]}>;
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+
+/// Checks that both algorithms give the same valid factorization of `f` over an extension field, and returns it.
+/// A square-free polynomial is irreducible exactly when its Berlekamp subalgebra is one-dimensional.
+fn check_factor_gf<const P: u64, const N: usize, const MODULUS: &'static [u64]>(f: &Polynomial<GF<P, N, T, MODULUS>, X>) -> Vec<(Polynomial<GF<P, N, T, MODULUS>, X>, u32)> {
+    let (leading, factors) = f.factor();
+    let product = factors.iter().fold(Polynomial::one() * leading, |product, (g, m)| (0..*m).fold(product, |product, _| &product * g));
+    assert_eq!(&product, f);
+    for (g, _) in &factors {
+        assert_eq!(berlekamp_basis(&DensePolynomial::from(g)).len(), 1, "{g:?} is reducible");
+        assert_eq!(g.leading(), Some(&GF::one()));
+    }
+    assert_eq!(f.factor_berlekamp(), (leading, factors.clone()));
+    factors
+}
+
+#[test]
+fn test_factor_over_extension_fields() {
+    // X^4 - X splits into linear factors over GF(4), one for each element.
+    let f = &Polynomial::<F4, X>::indeterminant_power(4) - &Polynomial::indeterminant_power(1);
+    let factors = check_factor_gf(&f);
+    let roots: Vec<F4> = factors.iter().map(|(g, _)| -g.coeff(0)).collect();
+    assert_eq!(roots, F4::elements().collect::<Vec<_>>());
+    // 4 has order 2 modulo 5, so the fifth cyclotomic polynomial splits into two quadratics over GF(4).
+    let f = &Polynomial::<F4, X>::indeterminant_power(5) - &Polynomial::one();
+    let factors = check_factor_gf(&f);
+    assert_eq!(factors.iter().map(|(g, m)| (g.deg(), *m)).collect::<Vec<_>>(), vec![(Some(1), 1), (Some(2), 1), (Some(2), 1)]);
+    // Over GF(8), with factors of equal degree so that splitting goes through the trace.
+    let f = &Polynomial::<F8, X>::indeterminant_power(7) - &Polynomial::one();
+    let factors = check_factor_gf(&f);
+    assert_eq!(factors.len(), 7);
+    let f = &Polynomial::<F8, X>::indeterminant_power(9) - &Polynomial::one();
+    let factors = check_factor_gf(&f);
+    assert_eq!(factors.iter().map(|(g, _)| g.deg().unwrap()).collect::<Vec<_>>(), vec![1, 2, 2, 2, 2]);
+}
+
+#[test]
+fn test_factor_powers_over_extension_fields() {
+    // (X + a)^3 = X^3 + a^3 over GF(9), whose cube root is only found through the inverse Frobenius.
+    let a = F9::generator();
+    let linear = Polynomial::<F9, X>::from_coeffs(&[a, F9::one()]);
+    let quadratic = Polynomial::<F9, X>::from_coeffs(&[a, a, F9::one()]);
+    let f = &(&(&linear * &linear) * &linear) * &quadratic;
+    let f = &f * &(&f * &quadratic);
+    let factors = check_factor_gf(&(&f * a));
+    assert!(factors.contains(&(linear, 6)));
+    // Squares in characteristic 2.
+    let b = F8::generator();
+    let g = Polynomial::<F8, X>::from_coeffs(&[b, F8::zero(), b, F8::one()]);
+    let factors = check_factor_gf(&(&g * &g));
+    assert!(factors.iter().all(|(_, m)| *m == 2));
+}
+
+#[test]
+#[should_panic(expected = "zero polynomial")]
+fn test_factor_zero() {
+    Poly::<5>::zero().factor();
+}
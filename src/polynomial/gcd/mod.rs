@@ -0,0 +1,202 @@
+//! Greatest common divisors of polynomials over a field, with Bézout cofactors.
+//!
+//! Euclid's algorithm is tracked as a product of the `2 x 2` matrices `[[0, 1], [1, -q]]` taking `(a, b)` to
+//! `(b, a mod b)`. For large degrees the half-GCD recursion finds the product of the first half of those steps from
+//! the top halves of `a` and `b` alone, so the whole GCD costs `O(log n)` multiplications of size `n`.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Error, Field, Indeterminate, Ring};
+
+use super::{dense::DensePolynomial, Polynomial};
+
+/// Below this degree, `half_gcd` runs Euclid's algorithm step by step.
+pub const HALF_GCD_THRESHOLD: usize = 64;
+
+/// `[[m[0][0], m[0][1]], [m[1][0], m[1][1]]]`, acting on column vectors `(a, b)`.
+type Matrix<R, const VAR: Indeterminate> = [[DensePolynomial<R, VAR>; 2]; 2];
+
+fn identity<R: Field, const VAR: Indeterminate>() -> Matrix<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    [[DensePolynomial::one(), DensePolynomial::zero()], [DensePolynomial::zero(), DensePolynomial::one()]]
+}
+
+/// The product `left * right`.
+fn compose<R: Field, const VAR: Indeterminate>(left: &Matrix<R, VAR>, right: &Matrix<R, VAR>) -> Matrix<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let entry = |i: usize, j: usize| &(&left[i][0] * &right[0][j]) + &(&left[i][1] * &right[1][j]);
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+
+/// The vector `m * (a, b)`.
+fn apply<R: Field, const VAR: Indeterminate>(m: &Matrix<R, VAR>, a: &DensePolynomial<R, VAR>, b: &DensePolynomial<R, VAR>) -> (DensePolynomial<R, VAR>, DensePolynomial<R, VAR>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    (&(&m[0][0] * a) + &(&m[0][1] * b), &(&m[1][0] * a) + &(&m[1][1] * b))
+}
+
+/// One step of Euclid's algorithm on `(a, b)` with `b` non-zero, returning its matrix and `(b, a mod b)`.
+fn euclid_step<R: Field, const VAR: Indeterminate>(a: &DensePolynomial<R, VAR>, b: &DensePolynomial<R, VAR>) -> (Matrix<R, VAR>, DensePolynomial<R, VAR>, DensePolynomial<R, VAR>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let (q, r) = a.div_rem(b);
+    ([[DensePolynomial::zero(), DensePolynomial::one()], [DensePolynomial::one(), -q]], b.clone(), r)
+}
+
+/// `a` divided by `VAR^k`, discarding the remainder.
+fn drop_low<R: Field, const VAR: Indeterminate>(a: &DensePolynomial<R, VAR>, k: usize) -> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    DensePolynomial::from_coeffs(&a.coeffs()[k.min(a.coeffs().len())..])
+}
+
+/// For `deg a >= deg b`, a product `M` of Euclid steps such that `M (a, b) = (a', b')` with `deg b' < m <= deg a'`,
+/// where `m = ceil(deg a / 2)`.
+fn half_gcd<R: Field, const VAR: Indeterminate>(a: &DensePolynomial<R, VAR>, b: &DensePolynomial<R, VAR>) -> Matrix<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let Some(deg_a) = a.deg() else {
+        return identity();
+    };
+    let m = deg_a.div_ceil(2);
+    let below_m = |p: &DensePolynomial<R, VAR>| p.deg().is_none_or(|deg| deg < m);
+    if below_m(b) {
+        return identity();
+    }
+    if deg_a < HALF_GCD_THRESHOLD {
+        let (mut matrix, mut a, mut b) = (identity(), a.clone(), b.clone());
+        while !below_m(&b) {
+            let (step, next_a, next_b) = euclid_step(&a, &b);
+            matrix = compose(&step, &matrix);
+            (a, b) = (next_a, next_b);
+        }
+        return matrix;
+    }
+    // The top halves determine the first half of the quotient sequence.
+    let first = half_gcd(&drop_low(a, m), &drop_low(b, m));
+    let (a, b) = apply(&first, a, b);
+    if below_m(&b) {
+        return first;
+    }
+    let (step, a, b) = euclid_step(&a, &b);
+    let first = compose(&step, &first);
+    if below_m(&b) {
+        return first;
+    }
+    let k = (2 * m).saturating_sub(a.deg().unwrap_or(0));
+    compose(&half_gcd(&drop_low(&a, k), &drop_low(&b, k)), &first)
+}
+
+/// The extended GCD of `a` and `b` before normalisation: `(g, s, t)` with `s a + t b = g` and `g` some associate of
+/// the GCD. With `fast` set, half-GCD steps are taken whenever both degrees reach `HALF_GCD_THRESHOLD`.
+fn unnormalised_extended_gcd<R: Field, const VAR: Indeterminate>(a: &DensePolynomial<R, VAR>, b: &DensePolynomial<R, VAR>, fast: bool) -> (DensePolynomial<R, VAR>, DensePolynomial<R, VAR>, DensePolynomial<R, VAR>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let (mut matrix, mut a, mut b) = (identity(), a.clone(), b.clone());
+    while b.deg().is_some() {
+        if fast && b.deg().is_some_and(|deg| deg >= HALF_GCD_THRESHOLD) && a.deg() > b.deg() {
+            let step = half_gcd(&a, &b);
+            (a, b) = apply(&step, &a, &b);
+            matrix = compose(&step, &matrix);
+            if b.deg().is_none() {
+                break;
+            }
+        }
+        let (step, next_a, next_b) = euclid_step(&a, &b);
+        matrix = compose(&step, &matrix);
+        (a, b) = (next_a, next_b);
+    }
+    let [[s, t], _] = matrix;
+    (a, s, t)
+}
+
+/// Scales `(g, s, t)` so that `g` is monic. A zero `g` is left alone.
+fn normalise<R: Field, const VAR: Indeterminate>((g, s, t): (DensePolynomial<R, VAR>, DensePolynomial<R, VAR>, DensePolynomial<R, VAR>)) -> (DensePolynomial<R, VAR>, DensePolynomial<R, VAR>, DensePolynomial<R, VAR>)
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    match g.leading().cloned() {
+        Some(leading) => (&g / &leading, &s / &leading, &t / &leading),
+        None => (g, s, t),
+    }
+}
+
+impl<R: Field, const VAR: Indeterminate> DensePolynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// The monic greatest common divisor of `self` and `other`. gcd(0, 0) is 0.
+    pub fn gcd(&self, other: &Self) -> Self {
+        self.extended_gcd(other).0
+    }
+
+    /// `(g, s, t)` with `g` the monic GCD and `s self + t other = g`.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        normalise(unnormalised_extended_gcd(self, other, true))
+    }
+
+    /// The monic least common multiple of `self` and `other`, which is zero if either is.
+    pub fn lcm(&self, other: &Self) -> Self {
+        if self.deg().is_none() || other.deg().is_none() {
+            return Self::zero();
+        }
+        (&(self / &self.gcd(other)) * other).monic()
+    }
+
+    /// The inverse of `self` modulo `modulus`, reduced below its degree, or `None` if they share a factor.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        let (g, s, _) = (self % modulus).extended_gcd(modulus);
+        if g == Self::one() {
+            Some(&s % modulus)
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: Field, const VAR: Indeterminate> Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// The monic greatest common divisor of `self` and `other`. gcd(0, 0) is 0.
+    pub fn gcd(&self, other: &Self) -> Self {
+        Self::from(DensePolynomial::from(self).gcd(&DensePolynomial::from(other)))
+    }
+
+    /// `(g, s, t)` with `g` the monic GCD and `s self + t other = g`.
+    pub fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (g, s, t) = DensePolynomial::from(self).extended_gcd(&DensePolynomial::from(other));
+        (Self::from(g), Self::from(s), Self::from(t))
+    }
+
+    /// The monic least common multiple of `self` and `other`, which is zero if either is.
+    pub fn lcm(&self, other: &Self) -> Self {
+        Self::from(DensePolynomial::from(self).lcm(&DensePolynomial::from(other)))
+    }
+
+    /// The inverse of `self` modulo `modulus`, reduced below its degree, or `None` if they share a factor.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        DensePolynomial::from(self).inverse_mod(&DensePolynomial::from(modulus)).map(Self::from)
+    }
+
+    /// `inverse_mod`, failing with `Error::DivisionByZero` for a zero modulus and `Error::NonUnit` if `self` and
+    /// `modulus` share a factor.
+    pub fn try_inverse_mod(&self, modulus: &Self) -> Result<Self, Error> {
+        if modulus.deg().is_none() {
+            return Err(Error::DivisionByZero);
+        }
+        self.inverse_mod(modulus).ok_or(Error::NonUnit)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,95 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::IMod, polynomial::test_utils::{random_coeffs, NTT_PRIME}, X};
+
+type Dense<const P: u64> = DensePolynomial<IMod<P>, X>;
+
+/// Deterministic pseudo-random polynomial of degree `len - 1`.
+fn random_poly<const P: u64>(len: usize, seed: u64) -> Dense<P> {
+    DensePolynomial::new(random_coeffs(len, seed))
+}
+
+/// Checks that `(g, s, t)` is a valid normalised extended GCD of `a` and `b` with `g` of the expected degree.
+fn check_extended_gcd<const P: u64>(a: &Dense<P>, b: &Dense<P>, (g, s, t): &(Dense<P>, Dense<P>, Dense<P>), deg: Option<usize>) {
+    assert_eq!(g.deg(), deg);
+    assert_eq!(&(&(s * a) + &(t * b)), g);
+    if deg.is_some() {
+        assert_eq!(g.leading(), Some(&IMod::one()));
+        assert_eq!(a % g, Dense::zero());
+        assert_eq!(b % g, Dense::zero());
+    }
+}
+
+#[test]
+fn test_small_gcd() {
+    // (X + 1)(X + 2) and (X + 1)(X + 3) over F5
+    let a: Polynomial<IMod<5>, X> = Polynomial::from_coeffs(&[IMod::new(2), IMod::new(3), IMod::one()]);
+    let b: Polynomial<IMod<5>, X> = Polynomial::from_coeffs(&[IMod::new(3), IMod::new(4), IMod::one()]);
+    let g = Polynomial::from_coeffs(&[IMod::one(), IMod::one()]);
+    assert_eq!(a.gcd(&b), g);
+    assert_eq!((&a * IMod::new(3)).gcd(&b), g);
+    let (g2, s, t) = a.extended_gcd(&b);
+    assert_eq!(g2, g);
+    assert_eq!(&(&s * &a) + &(&t * &b), g);
+    assert_eq!(a.lcm(&b), Polynomial::from_coeffs(&[IMod::new(1), IMod::new(1), IMod::new(1), IMod::one()]));
+    // Degenerate inputs.
+    assert_eq!(a.gcd(&Polynomial::zero()), a);
+    assert_eq!(Polynomial::<IMod<5>, X>::zero().gcd(&Polynomial::zero()), Polynomial::zero());
+    assert_eq!(a.gcd(&Polynomial::one()), Polynomial::one());
+    assert_eq!(a.lcm(&Polynomial::zero()), Polynomial::zero());
+}
+
+#[test]
+fn test_half_gcd_agrees_with_euclid() {
+    for (i, &(m, n, common)) in [(200, 150, 0), (300, 299, 40), (500, 100, 70), (260, 260, 130), (700, 650, 1)].iter().enumerate() {
+        let shared = random_poly::<NTT_PRIME>(common + 1, 100 + i as u64).monic();
+        let a = &random_poly::<NTT_PRIME>(m - common, i as u64) * &shared;
+        let b = &random_poly::<NTT_PRIME>(n - common, 50 + i as u64) * &shared;
+        let fast = normalise(unnormalised_extended_gcd(&a, &b, true));
+        let slow = normalise(unnormalised_extended_gcd(&a, &b, false));
+        check_extended_gcd(&a, &b, &fast, Some(common));
+        assert_eq!(fast.0, slow.0, "degrees {m} and {n}");
+        assert_eq!(fast.0, shared);
+    }
+    // Characteristic 2, where the half-GCD matrices multiply by Karatsuba.
+    let a = random_poly::<2>(400, 1);
+    let b = random_poly::<2>(380, 2);
+    let fast = a.extended_gcd(&b);
+    check_extended_gcd(&a, &b, &fast, normalise(unnormalised_extended_gcd(&a, &b, false)).0.deg());
+}
+
+#[test]
+fn test_inverse_mod() {
+    let f = random_poly::<NTT_PRIME>(300, 7);
+    for (i, len) in [1, 2, 150, 299, 600].into_iter().enumerate() {
+        let a = random_poly::<NTT_PRIME>(len, 20 + i as u64);
+        let inverse = a.inverse_mod(&f).expect("A random polynomial is almost surely coprime to f");
+        assert!(inverse.deg() < f.deg());
+        assert_eq!(&(&a * &inverse) % &f, Dense::one());
+    }
+    // Anything sharing a factor with the modulus has no inverse.
+    let factor = random_poly::<NTT_PRIME>(10, 30);
+    assert_eq!((&factor * &random_poly(5, 31)).inverse_mod(&(&factor * &f)), None);
+    assert_eq!(Dense::<NTT_PRIME>::zero().inverse_mod(&f), None);
+    // X^2 + 1 is irreducible over F3, and X^2 = -1 there, so X^-1 = -X = 2X.
+    let modulus: Polynomial<IMod<3>, X> = Polynomial::from_coeffs(&[IMod::one(), IMod::zero(), IMod::one()]);
+    assert_eq!(Polynomial::indeterminant_power(1).inverse_mod(&modulus), Some(Polynomial::from_coeffs(&[IMod::zero(), IMod::new(2)])));
+}
+
+#[test]
+fn test_try_inverse_mod() {
+    let modulus: Polynomial<IMod<3>, X> = Polynomial::from_coeffs(&[IMod::one(), IMod::zero(), IMod::one()]);
+    let x = Polynomial::indeterminant_power(1);
+    assert_eq!(x.try_inverse_mod(&modulus), Ok(Polynomial::from_coeffs(&[IMod::zero(), IMod::new(2)])));
+    assert_eq!(x.try_inverse_mod(&Polynomial::zero()), Err(Error::DivisionByZero));
+    assert_eq!(x.try_inverse_mod(&(&x * &modulus)), Err(Error::NonUnit));
+}
+
+#[test]
+fn test_gcd_over_extension_field() {
+    type F9 = GF<3, 2, X, {&[2, 2, 1]}>;
+    let a: DensePolynomial<F9, X> = DensePolynomial::new(vec![F9::generator(), F9::one(), F9::one()]);
+    let b: DensePolynomial<F9, X> = DensePolynomial::new(vec![F9::one(), F9::generator()]);
+    let (g, s, t) = (&a * &b).extended_gcd(&(&b * &b));
+    assert_eq!(g, b.monic());
+    assert_eq!(&(&s * &(&a * &b)) + &(&t * &(&b * &b)), g);
+}
@@ -0,0 +1,70 @@
+use crate::{modulo_ints::IMod, tools::distinct_prime_factors, Indeterminate, Ring};
+
+use super::Polynomial;
+
+impl<const P: u64, const VAR: Indeterminate> Polynomial<IMod<P>, VAR> {
+    /// Rabin's irreducibility test. A polynomial `f` of degree `n > 0` over `IMod<P>` is irreducible exactly when
+    /// `f` divides `VAR^(P^n) - VAR` and, for every prime `q` dividing `n`, gcd(`VAR^(P^(n/q)) - VAR`, `f`) = 1.
+    ///
+    /// Constants, including zero, are not irreducible.
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.deg() {
+            None | Some(0) => return false,
+            Some(n) => n,
+        };
+        let x = &Self::indeterminant_power(1) % self;
+        // frobenius[k] = VAR^(P^k) mod f
+        let mut frobenius = vec![x.clone()];
+        for k in 1..=n {
+            let next = frobenius[k-1].pow_mod(P as u128, self);
+            frobenius.push(next);
+        }
+        if frobenius[n] != x {
+            return false;
+        }
+        distinct_prime_factors(n as u128).into_iter().all(|q| {
+            let g = (&frobenius[n / q as usize] - &x).gcd(self);
+            g.deg() == Some(0)
+        })
+    }
+
+    /// Whether this polynomial is primitive, i.e. irreducible with `VAR` generating the multiplicative group of
+    /// `IMod<P>[VAR]/(self)`. Equivalently, `VAR` has multiplicative order exactly `P^n - 1` modulo `self`.
+    pub fn is_primitive(&self) -> bool {
+        if !self.is_irreducible() {
+            return false;
+        }
+        let n = self.deg().expect("Irreducible polynomials are non-zero") as u32;
+        let order = (P as u128).checked_pow(n).expect("P^n must fit in a u128") - 1;
+        let x = Self::indeterminant_power(1);
+        let one = &Self::one() % self;
+        // `VAR` must be a unit (it is not when `self` is a multiple of `VAR`) and of full order.
+        x.pow_mod(order, self) == one
+            && distinct_prime_factors(order).into_iter().all(|r| x.pow_mod(order / r, self) != one)
+    }
+
+    /// Every monic polynomial of degree `n`, enumerated by reading the coefficients of `VAR^0, ..., VAR^(n-1)` as
+    /// the digits of a base-`P` counter, least significant first. Sparse polynomials whose non-zero terms have low
+    /// degree therefore come first.
+    fn monic_polynomials(n: usize) -> impl Iterator<Item = Self> {
+        let count = (P as u128).checked_pow(n as u32).expect("P^n must fit in a u128");
+        (0..count).map(move |mut index| {
+            let mut coeffs = vec![IMod::one(); n + 1];
+            for coeff in coeffs.iter_mut().take(n) {
+                *coeff = IMod::new(index % P as u128);
+                index /= P as u128;
+            }
+            Self::from_coeffs(&coeffs)
+        })
+    }
+
+    /// All monic primitive polynomials of degree `n` over `IMod<P>`, in the order of `monic_polynomials`.
+    pub fn primitive_polynomials(n: usize) -> impl Iterator<Item = Self> {
+        Self::monic_polynomials(n).filter(Self::is_primitive)
+    }
+
+    /// A monic primitive polynomial of degree `n` over `IMod<P>`, preferring sparse ones such as trinomials.
+    pub fn find_primitive(n: usize) -> Self {
+        Self::primitive_polynomials(n).next().expect("Every finite field has a primitive polynomial")
+    }
+}
@@ -1,9 +1,8 @@
 
 use std::{collections::HashMap, ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign}};
 
-use crate::{Field, Indeterminate, Ring};
+use crate::{Error, Field, Indeterminate, Ring};
 
-#[derive(Debug)]
 pub struct Polynomial<R: Ring, const VAR: Indeterminate>
 where
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
@@ -28,6 +27,12 @@ where
         }
     }
 
+    /// `new`, for use alongside the other checked constructors. It never fails, since any map of degrees to
+    /// coefficients is a polynomial once its zero coefficients are dropped.
+    pub fn try_new(vals: &HashMap<usize,R>) -> Result<Self, Error> {
+        Ok(Self::new(vals))
+    }
+
     fn prune_zeros(&mut self) {
         let Self {vals} = self;
         let keys = vals.keys().cloned().collect::<Vec<_>>();
@@ -49,7 +54,7 @@ where
     }
 }
 
-impl<'a, 'b, R, const VAR: Indeterminate> Add<&'b Polynomial<R,VAR>> for &'a Polynomial<R,VAR>
+impl<'b, R, const VAR: Indeterminate> Add<&'b Polynomial<R,VAR>> for &Polynomial<R,VAR>
 where 
     R: Ring,
     for<'c, 'd> &'c R : Add<&'d R, Output = R> + Mul<&'d R, Output = R> + Sub<&'d R, Output = R> + Neg<Output = R>
@@ -85,7 +90,7 @@ where
     }
 }
 
-impl<'c,'d, R, const VAR: Indeterminate> Mul<&'c Polynomial<R,VAR>> for &'d Polynomial<R,VAR> 
+impl<'c, R, const VAR: Indeterminate> Mul<&'c Polynomial<R,VAR>> for &Polynomial<R,VAR> 
 where 
     R: Ring,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
@@ -93,6 +98,10 @@ where
     type Output = Polynomial<R, VAR>;
 
     fn mul(self, rhs: &'c Polynomial<R,VAR>) -> Self::Output {
+        if self.is_dense() && rhs.is_dense() && self.vals.len().min(rhs.vals.len()) >= multiplication::KARATSUBA_THRESHOLD {
+            let (a, b) = (dense::DensePolynomial::from(self), dense::DensePolynomial::from(rhs));
+            return Self::Output::from_coeffs(&R::multiply_coefficients(a.coeffs(), b.coeffs()));
+        }
         let mut vals = HashMap::new();
         for (i, x) in &self.vals {
             for (j, y) in &rhs.vals {
@@ -148,7 +157,7 @@ where
     }
 }
 
-impl<'c,'d,R,const VAR: Indeterminate> Sub<&'c Polynomial<R,VAR>> for &'d Polynomial<R, VAR> 
+impl<'c, R, const VAR: Indeterminate> Sub<&'c Polynomial<R,VAR>> for &Polynomial<R, VAR> 
 where 
     R: Ring,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
@@ -275,6 +284,25 @@ where
     }
 }
 
+/// `n c`, by doubling, since a general ring has no map from the integers.
+fn multiple<R: Ring>(c: &R, mut n: usize) -> R
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let mut result = R::zero();
+    let mut base = c.clone();
+    while n > 0 {
+        if n % 2 == 1 {
+            result += &base;
+        }
+        n >>= 1;
+        if n > 0 {
+            base = &base + &base;
+        }
+    }
+    result
+}
+
 impl<R, const VAR: Indeterminate> Polynomial<R, VAR> 
 where 
     R: Ring,
@@ -284,7 +312,7 @@ where
         self.vals.keys().max().cloned()
     }
 
-    pub fn leading<'a>(&'a self) -> Option<&'a R> {
+    pub fn leading(&self) -> Option<&R> {
         self.vals.get(&self.deg()?)
     }
 
@@ -293,9 +321,58 @@ where
         hash_map.insert(pow,R::one());
         Self::new(&hash_map)
     }
+
+    /// Builds a polynomial from its coefficients, where `coeffs[i]` is the coefficient of `VAR^i`.
+    pub fn from_coeffs(coeffs: &[R]) -> Self {
+        Self::new(&coeffs.iter().cloned().enumerate().collect())
+    }
+
+    /// The coefficient of `VAR^i`, which is zero for any power not present.
+    pub fn coeff(&self, i: usize) -> R {
+        self.vals.get(&i).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// The non-zero terms as `(degree, coefficient)` pairs, in ascending order of degree.
+    pub fn terms<'a>(&'a self) -> impl Iterator<Item = (usize, &'a R)> {
+        let mut terms: Vec<(usize, &'a R)> = self.vals.iter().map(|(&deg, c)| (deg, c)).collect();
+        terms.sort_unstable_by_key(|&(deg, _)| deg);
+        terms.into_iter()
+    }
+
+    /// The formal derivative.
+    pub fn derivative(&self) -> Self {
+        Self::new(&self.vals.iter().filter(|&(&deg, _)| deg > 0).map(|(&deg, c)| (deg - 1, multiple(c, deg))).collect())
+    }
+
+    /// `VAR^n self(1/VAR)`, i.e. the coefficients of `VAR^0, ..., VAR^n` in reverse order. Terms above `VAR^n` are
+    /// dropped.
+    pub fn reverse(&self, n: usize) -> Self {
+        Self::new(&self.vals.iter().filter(|&(&deg, _)| deg <= n).map(|(&deg, c)| (n - deg, c.clone())).collect())
+    }
+
+    /// `self mod VAR^n`, keeping only the terms of degree below `n`.
+    pub fn truncate(&self, n: usize) -> Self {
+        Self::new(&self.vals.iter().filter(|&(&deg, _)| deg < n).map(|(&deg, c)| (deg, c.clone())).collect())
+    }
+
+    /// `self * VAR^k`.
+    pub fn shift_left(&self, k: usize) -> Self {
+        Self::new(&self.vals.iter().map(|(&deg, c)| (deg + k, c.clone())).collect())
+    }
+
+    /// `self / VAR^k`, discarding the terms of degree below `k`.
+    pub fn shift_right(&self, k: usize) -> Self {
+        Self::new(&self.vals.iter().filter(|&(&deg, _)| deg >= k).map(|(&deg, c)| (deg - k, c.clone())).collect())
+    }
+
+    /// Whether at least half of the coefficients up to the degree are non-zero, so that multiplying densely beats
+    /// multiplying term by term.
+    fn is_dense(&self) -> bool {
+        self.deg().is_some_and(|deg| 2 * self.vals.len() > deg)
+    }
 }
 
-impl<'c, 'd, R, const VAR: Indeterminate> Mul<&'c R> for &'d Polynomial<R, VAR> 
+impl<'c, R, const VAR: Indeterminate> Mul<&'c R> for &Polynomial<R, VAR> 
 where 
     R: Ring,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
@@ -307,7 +384,7 @@ where
     }
 }
 
-impl<'d, R, const VAR: Indeterminate> Mul<R> for &'d Polynomial<R, VAR> 
+impl<R, const VAR: Indeterminate> Mul<R> for &Polynomial<R, VAR> 
 where 
     R: Ring,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
@@ -319,7 +396,7 @@ where
     }
 }
 
-impl<'c, 'd, R, const VAR: Indeterminate> Div<&'c R> for &'d Polynomial<R, VAR> 
+impl<'c, R, const VAR: Indeterminate> Div<&'c R> for &Polynomial<R, VAR> 
 where 
     R: Field,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
@@ -331,7 +408,7 @@ where
     }
 }
 
-impl<'d, R, const VAR: Indeterminate> Div<R> for &'d Polynomial<R, VAR> 
+impl<R, const VAR: Indeterminate> Div<R> for &Polynomial<R, VAR> 
 where 
     R: Field,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
@@ -343,7 +420,7 @@ where
     }
 }
 
-impl<'c, 'd, R, const VAR: Indeterminate> Div<&'c Polynomial<R, VAR>> for &'d Polynomial<R, VAR>
+impl<'c, R, const VAR: Indeterminate> Div<&'c Polynomial<R, VAR>> for &Polynomial<R, VAR>
 where 
     R: Field,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
@@ -351,31 +428,11 @@ where
     type Output = Polynomial<R, VAR>;
 
     fn div(self, rhs: &'c Polynomial<R, VAR>) -> Self::Output {
-        let mut out = Self::Output::zero();
-        let mut curr = self.clone();
-        let mut curr_deg = match curr.deg() {
-            Some(x) => x,
-            None => return out,
-        };
-        let rhs_deg = rhs.deg().expect("Hey, stop trying to divide by zero!");
-        while curr_deg >= rhs_deg {
-            let curr_leading = curr.leading().expect("er... shouldn't this be impossible to get to?");
-            let rhs_leading = rhs.leading().expect("Again, this should be impossible to get to.");
-            let factor = Polynomial::indeterminant_power(curr_deg-rhs_deg) * (curr_leading/rhs_leading);
-            let rhs_multiple = rhs*&factor;
-            assert_eq!(rhs_multiple.deg(),curr.deg());
-            curr -= rhs_multiple;
-            out += factor;
-            curr_deg = match curr.deg() {
-                Some(x) => x,
-                None => return out,
-            };
-        }
-        out
+        self.div_rem(rhs).0
     }
 }
 
-impl<'c, 'd, R, const VAR: Indeterminate> Rem<&'c Polynomial<R, VAR>> for &'d Polynomial<R, VAR>
+impl<'c, R, const VAR: Indeterminate> Rem<&'c Polynomial<R, VAR>> for &Polynomial<R, VAR>
 where 
     R: Field,
     for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
@@ -383,9 +440,62 @@ where
     type Output = Polynomial<R, VAR>;
 
     fn rem(self, rhs: &'c Polynomial<R, VAR>) -> Self::Output {
-        let x = self/rhs;
-        self - &(&x*rhs)
-        // self - (self/rhs)*rhs
+        self.div_rem(rhs).1
+    }
+}
+
+impl<R, const VAR: Indeterminate> Polynomial<R, VAR>
+where
+    R: Field,
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// This polynomial scaled to have leading coefficient one. The zero polynomial is returned unchanged.
+    pub fn monic(&self) -> Self {
+        match self.leading() {
+            Some(leading) => self / leading,
+            None => self.clone(),
+        }
+    }
+
+    /// Quotient and remainder in one pass, working on dense coefficients and switching to Newton-iteration division
+    /// for large degrees.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (a, b) = (dense::DensePolynomial::from(self), dense::DensePolynomial::from(rhs));
+        let (quotient, remainder) = a.div_rem(&b);
+        (Self::from(quotient), Self::from(remainder))
+    }
+
+    /// `div_rem`, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_div_rem(&self, rhs: &Self) -> Result<(Self, Self), Error> {
+        if rhs.deg().is_none() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(self.div_rem(rhs))
+    }
+
+    /// The quotient of `self` by `rhs`, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, Error> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// The remainder of `self` by `rhs`, or `Error::DivisionByZero` when `rhs` is zero.
+    pub fn checked_rem(&self, rhs: &Self) -> Result<Self, Error> {
+        self.checked_div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+
+    /// The inverse in the polynomial ring, which exists only for non-zero constants. Fails with
+    /// `Error::DivisionByZero` for zero and `Error::NonUnit` for any polynomial of positive degree.
+    pub fn try_inverse(&self) -> Result<Self, Error> {
+        match self.deg() {
+            None => Err(Error::DivisionByZero),
+            Some(0) => Ok(Self::from_coeffs(&[&R::one() / &self.coeff(0)])),
+            Some(_) => Err(Error::NonUnit),
+        }
+    }
+
+    /// `self^exp mod modulus`, by repeated squaring with a precomputed `division::Modulus`.
+    pub fn pow_mod(&self, exp: u128, modulus: &Self) -> Self {
+        Self::from(dense::DensePolynomial::from(self).pow_mod(exp, &dense::DensePolynomial::from(modulus)))
     }
 }
 
@@ -437,5 +547,18 @@ where
     }
 }
 
+pub mod dense;
+mod display;
+pub mod division;
+pub mod evaluation;
+pub mod factor;
+pub mod gcd;
+mod irreducible;
+pub mod multiplication;
+pub mod parse;
+pub mod roots;
+
 #[cfg(test)]
 mod test;
+#[cfg(test)]
+mod test_utils;
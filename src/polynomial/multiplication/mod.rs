@@ -0,0 +1,271 @@
+//! Polynomial multiplication algorithms on coefficient slices, lowest power first.
+//!
+//! Every function here returns exactly the schoolbook product, with length `a.len() + b.len() - 1` (or empty if
+//! either input is), so trailing zeros are left for the caller to prune. `Ring::multiply_coefficients` picks between
+//! them: Karatsuba works over any ring, Toom-3 needs 2 and 3 to be invertible so only applies to fields of
+//! characteristic above 3, and the number-theoretic transform needs `IMod<P>` with a large power of two dividing
+//! `P - 1`.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{modulo_ints::IMod, FiniteField, Field, Ring};
+
+/// Below this many coefficients in the shorter operand, Karatsuba falls back to the schoolbook product.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Below this many coefficients in the shorter operand, Toom-3 falls back to Karatsuba.
+pub const TOOM3_THRESHOLD: usize = 96;
+
+/// From this many coefficients in the shorter operand, `IMod<P>` products use the number-theoretic transform if `P`
+/// allows it.
+pub const NTT_THRESHOLD: usize = 64;
+
+/// The schoolbook product, in `a.len() * b.len()` ring multiplications.
+pub fn schoolbook<R: Ring>(a: &[R], b: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] += x * y;
+        }
+    }
+    out
+}
+
+/// Adds `src` into `out` starting at `offset`. Entries past the end of `out` must be zero and are dropped.
+fn add_at<R: Ring>(out: &mut [R], offset: usize, src: &[R])
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    for (x, y) in out.iter_mut().skip(offset).zip(src) {
+        *x += y;
+    }
+}
+
+/// `a + b`, as long as the longer of the two.
+fn add<R: Ring>(a: &[R], b: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut out = long.to_vec();
+    add_at(&mut out, 0, short);
+    out
+}
+
+/// `a - b`, as long as the longer of the two.
+fn sub<R: Ring>(a: &[R], b: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let mut out = a.to_vec();
+    if out.len() < b.len() {
+        out.resize(b.len(), R::zero());
+    }
+    for (x, y) in out.iter_mut().zip(b) {
+        *x -= y;
+    }
+    out
+}
+
+/// `a * c` for a scalar `c`.
+fn scale<R: Ring>(a: &[R], c: &R) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    a.iter().map(|x| x * c).collect()
+}
+
+/// The `i`th of the consecutive blocks of length `k` in `a`, which is shorter or empty at the end.
+fn block<R>(a: &[R], i: usize, k: usize) -> &[R] {
+    &a[(i * k).min(a.len())..((i + 1) * k).min(a.len())]
+}
+
+/// Multiplies operands of very different lengths by cutting the longer into blocks the length of the shorter, so
+/// that `mul` only sees balanced products.
+fn unbalanced<R: Ring>(long: &[R], short: &[R], mul: impl Fn(&[R], &[R]) -> Vec<R>) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let mut out = vec![R::zero(); long.len() + short.len() - 1];
+    for (i, chunk) in long.chunks(short.len()).enumerate() {
+        add_at(&mut out, i * short.len(), &mul(chunk, short));
+    }
+    out
+}
+
+/// Karatsuba's product, using three half-size products in place of four.
+pub fn karatsuba<R: Ring>(a: &[R], b: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.len() < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    if long.len() >= 2 * short.len() {
+        return unbalanced(long, short, karatsuba);
+    }
+    // a = a0 + a1 X^m and b = b0 + b1 X^m, where b1 may be empty.
+    let m = long.len().div_ceil(2);
+    let (a0, a1) = long.split_at(m);
+    let (b0, b1) = short.split_at(m);
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let z1 = sub(&sub(&karatsuba(&add(a0, a1), &add(b0, b1)), &z0), &z2);
+    let mut out = vec![R::zero(); a.len() + b.len() - 1];
+    add_at(&mut out, 0, &z0);
+    add_at(&mut out, m, &z1);
+    add_at(&mut out, 2 * m, &z2);
+    out
+}
+
+/// The Toom-3 product, using five third-size products in place of nine.
+///
+/// The operands are evaluated at `0, 1, -1, -2` and infinity and interpolated with Bodrato's sequence, which divides
+/// by 2 and 3. Fields of characteristic 2 or 3 therefore fall back to Karatsuba.
+pub fn toom3<R: Field>(a: &[R], b: &[R]) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let two = &R::one() + &R::one();
+    let three = &two + &R::one();
+    if two == R::zero() || three == R::zero() {
+        return karatsuba(a, b);
+    }
+    toom3_inner(a, b, &(&R::one() / &two), &(&R::one() / &three))
+}
+
+fn toom3_inner<R: Field>(a: &[R], b: &[R], half: &R, third: &R) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if short.len() < TOOM3_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    let recurse = |x: &[R], y: &[R]| toom3_inner(x, y, half, third);
+    if long.len() >= 2 * short.len() {
+        return unbalanced(long, short, recurse);
+    }
+    let k = long.len().div_ceil(3);
+    let two = &R::one() + &R::one();
+    // The values of a = a0 + a1 Y + a2 Y^2 (with Y = X^k) at 0, 1, -1, -2 and infinity.
+    let evaluate = |s: &[R]| {
+        let (s0, s1, s2) = (block(s, 0, k), block(s, 1, k), block(s, 2, k));
+        let even = add(s0, s2);
+        let at_minus_one = sub(&even, s1);
+        let at_minus_two = sub(&scale(&add(&at_minus_one, s2), &two), s0);
+        [s0.to_vec(), add(&even, s1), at_minus_one, at_minus_two, s2.to_vec()]
+    };
+    let [p0, p1, pm1, pm2, pinf] = evaluate(long);
+    let [q0, q1, qm1, qm2, qinf] = evaluate(short);
+    let r0 = recurse(&p0, &q0);
+    let r1 = recurse(&p1, &q1);
+    let rm1 = recurse(&pm1, &qm1);
+    let rm2 = recurse(&pm2, &qm2);
+    let rinf = recurse(&pinf, &qinf);
+    // Bodrato's interpolation sequence.
+    let c3 = scale(&sub(&rm2, &r1), third);
+    let c1 = scale(&sub(&r1, &rm1), half);
+    let c2 = sub(&rm1, &r0);
+    let c3 = add(&scale(&sub(&c2, &c3), half), &scale(&rinf, &two));
+    let c2 = sub(&add(&c2, &c1), &rinf);
+    let c1 = sub(&c1, &c3);
+    let mut out = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, c) in [r0, c1, c2, c3, rinf].iter().enumerate() {
+        add_at(&mut out, i * k, c);
+    }
+    out
+}
+
+/// A primitive `2^log`th root of unity in `IMod<P>`, if `2^log` divides `P - 1`.
+fn root_of_unity<const P: u64>(log: u32) -> Option<IMod<P>> {
+    if (P - 1).trailing_zeros() < log {
+        return None;
+    }
+    if log == 0 {
+        return Some(IMod::one());
+    }
+    // c^((P - 1) / 2^log) has order exactly 2^log whenever c is a quadratic non-residue, which half of all c are.
+    (2..P as u128)
+        .map(|c| IMod::<P>::new(c).pow((P as u128 - 1) >> log))
+        .find(|w| w.pow(1 << (log - 1)) != IMod::one())
+}
+
+/// In-place iterative Cooley-Tukey transform of `values`, whose length is a power of two, evaluating at the powers of
+/// the primitive `values.len()`th root of unity `root`.
+fn ntt<const P: u64>(values: &mut [IMod<P>], root: IMod<P>) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n {
+        let step = root.pow((n / len) as u128);
+        for start in (0..n).step_by(len) {
+            let mut w = IMod::one();
+            for i in start..start + len / 2 {
+                let u = values[i];
+                let v = values[i + len / 2] * w;
+                values[i] = u + v;
+                values[i + len / 2] = u - v;
+                w *= step;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// The product by number-theoretic transform in `O(n log n)` operations, or `None` when `P - 1` is not divisible by
+/// a power of two at least the length of the product.
+pub fn ntt_multiply<const P: u64>(a: &[IMod<P>], b: &[IMod<P>]) -> Option<Vec<IMod<P>>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(Vec::new());
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let root = root_of_unity::<P>(size.trailing_zeros())?;
+    let mut fa = a.to_vec();
+    fa.resize(size, IMod::zero());
+    let mut fb = b.to_vec();
+    fb.resize(size, IMod::zero());
+    ntt(&mut fa, root);
+    ntt(&mut fb, root);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x *= y;
+    }
+    // The inverse transform is the transform at the inverse root, divided by the size.
+    ntt(&mut fa, root.inverse()?);
+    let size_inverse = IMod::<P>::new(size as u128).inverse()?;
+    fa.truncate(len);
+    for x in fa.iter_mut() {
+        *x *= size_inverse;
+    }
+    Some(fa)
+}
+
+/// The product over `IMod<P>`, by transform when it is large and `P` allows it, and otherwise by Toom-3 or Karatsuba.
+pub fn multiply_imod<const P: u64>(a: &[IMod<P>], b: &[IMod<P>]) -> Vec<IMod<P>> {
+    if a.len().min(b.len()) >= NTT_THRESHOLD && let Some(product) = ntt_multiply(a, b) {
+        return product;
+    }
+    toom3(a, b)
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,73 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::{montgomery::Montgomery, zmod::ZMod}, polynomial::{dense::DensePolynomial, test_utils::{random_imod, random_values, NTT_PRIME}, Polynomial}, X};
+
+/// Operand lengths on either side of every threshold, including unbalanced pairs.
+const SIZES: &[(usize, usize)] = &[(0, 5), (1, 1), (7, 3), (31, 32), (32, 32), (33, 70), (64, 64), (95, 97), (96, 96), (150, 151), (200, 67), (300, 1), (257, 300)];
+
+#[test]
+fn test_karatsuba_matches_schoolbook() {
+    for (i, &(m, n)) in SIZES.iter().enumerate() {
+        let a = random_imod::<7>(m, i as u64);
+        let b = random_imod::<7>(n, 100 + i as u64);
+        assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b), "lengths {m} and {n}");
+    }
+    // Karatsuba needs no division, so it works over any ring, including Z/12.
+    let a: Vec<ZMod<12>> = random_values(100, 1).into_iter().map(ZMod::new).collect();
+    let b: Vec<ZMod<12>> = random_values(81, 2).into_iter().map(ZMod::new).collect();
+    assert_eq!(karatsuba(&a, &b), schoolbook(&a, &b));
+}
+
+#[test]
+fn test_toom3_matches_schoolbook() {
+    for (i, &(m, n)) in SIZES.iter().enumerate() {
+        let a = random_imod::<NTT_PRIME>(m, i as u64);
+        let b = random_imod::<NTT_PRIME>(n, 100 + i as u64);
+        assert_eq!(toom3(&a, &b), schoolbook(&a, &b), "lengths {m} and {n}");
+    }
+    let a = random_imod::<5>(400, 3);
+    let b = random_imod::<5>(390, 4);
+    assert_eq!(toom3(&a, &b), schoolbook(&a, &b));
+    // Characteristic 2 and 3 fall back to Karatsuba.
+    let a = random_imod::<3>(200, 5);
+    let b = random_imod::<3>(200, 6);
+    assert_eq!(toom3(&a, &b), schoolbook(&a, &b));
+    let a = random_imod::<2>(200, 7);
+    assert_eq!(toom3(&a, &a), schoolbook(&a, &a));
+}
+
+#[test]
+fn test_ntt_matches_schoolbook() {
+    for (i, &(m, n)) in SIZES.iter().enumerate() {
+        let a = random_imod::<NTT_PRIME>(m, i as u64);
+        let b = random_imod::<NTT_PRIME>(n, 100 + i as u64);
+        assert_eq!(ntt_multiply(&a, &b), Some(schoolbook(&a, &b)), "lengths {m} and {n}");
+    }
+    // 7 - 1 = 2 * 3 only admits transforms of length 2.
+    let a = random_imod::<7>(100, 1);
+    assert_eq!(ntt_multiply(&a, &a), None);
+    assert_eq!(ntt_multiply(&a[..1], &a[..2]), Some(schoolbook(&a[..1], &a[..2])));
+}
+
+#[test]
+fn test_polynomial_products_match_schoolbook() {
+    // Each coefficient type dispatches to a different algorithm through `Ring::multiply_coefficients`.
+    let a = random_imod::<NTT_PRIME>(1000, 8);
+    let b = random_imod::<NTT_PRIME>(700, 9);
+    let product = DensePolynomial::<_, X>::from_coeffs(&a) * DensePolynomial::from_coeffs(&b);
+    assert_eq!(product.coeffs(), schoolbook(&a, &b));
+    let sparse = Polynomial::<_, X>::from_coeffs(&a) * Polynomial::from_coeffs(&b);
+    assert_eq!(DensePolynomial::from(sparse), product);
+
+    let a: Vec<Montgomery<101>> = random_values(300, 10).into_iter().map(Montgomery::new).collect();
+    let b: Vec<Montgomery<101>> = random_values(250, 11).into_iter().map(Montgomery::new).collect();
+    assert_eq!(Montgomery::multiply_coefficients(&a, &b), schoolbook(&a, &b));
+
+    type GF25 = GF<5, 2, X>;
+    let a: Vec<GF25> = random_values(200, 12).into_iter().map(|i| GF25::from_index(i % 25)).collect();
+    let b: Vec<GF25> = random_values(180, 13).into_iter().map(|i| GF25::from_index(i % 25)).collect();
+    assert_eq!(GF25::multiply_coefficients(&a, &b), schoolbook(&a, &b));
+
+    // Sparse operands keep the term-by-term product.
+    let sparse: Polynomial<IMod<7>, X> = &Polynomial::indeterminant_power(1000) + &Polynomial::one();
+    assert_eq!(&sparse * &sparse, &(&Polynomial::indeterminant_power(2000) + &(&Polynomial::indeterminant_power(1000) * IMod::new(2))) + &Polynomial::one());
+}
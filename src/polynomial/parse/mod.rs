@@ -0,0 +1,322 @@
+//! Parsing polynomials from text such as `3X^4 - X + 2`.
+//!
+//! A polynomial is a sum or difference of terms, each an optional coefficient followed by an optional power of the
+//! variable, as in `3X^4`, `3*X^4`, `X` or `2`. Integer coefficients are read with the coefficient ring's `FromStr`;
+//! any other coefficient, such as a `GF` element `T + 1`, is written in parentheses, as `Display` prints it. The
+//! variable must be the polynomial's own `VAR`, so `"Y + 1"` does not parse as a polynomial in `X`. Whitespace
+//! between tokens is ignored and repeated powers are summed.
+
+use std::{collections::HashMap, error::Error, fmt::{self, Display}, iter::Peekable, ops::{Add, Mul, Neg, Sub}, str::{Chars, FromStr}};
+
+use crate::{Indeterminate, Ring};
+
+use super::Polynomial;
+
+/// The reason a string is not a valid polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePolynomialError {
+    /// The string has nothing but whitespace.
+    Empty,
+    /// The input ended where a term was still expected, as in `X +`.
+    UnexpectedEnd,
+    /// A character that cannot appear at this point.
+    UnexpectedCharacter(char),
+    /// A variable other than the polynomial's own.
+    WrongVariable { expected: char, found: char },
+    /// A coefficient that the coefficient ring failed to parse.
+    InvalidCoefficient(String),
+    /// An exponent that is missing or does not fit in a `usize`.
+    InvalidExponent(String),
+    /// A parenthesised coefficient that is never closed.
+    UnclosedParenthesis,
+}
+
+// Display implementation
+impl Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse a polynomial from an empty string"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of input, expected a term"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character '{c}'"),
+            Self::WrongVariable { expected, found } => write!(f, "expected the variable '{expected}', found '{found}'"),
+            Self::InvalidCoefficient(text) => write!(f, "invalid coefficient \"{text}\""),
+            Self::InvalidExponent(text) => write!(f, "invalid exponent \"{text}\""),
+            Self::UnclosedParenthesis => write!(f, "unclosed parenthesis in a coefficient"),
+        }
+    }
+}
+
+impl Error for ParsePolynomialError {}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn digits(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut text = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        text.push(c);
+    }
+    text
+}
+
+/// The text up to the parenthesis closing one that has just been read.
+fn parenthesised(chars: &mut Peekable<Chars<'_>>) -> Result<String, ParsePolynomialError> {
+    let mut depth = 1;
+    let mut text = String::new();
+    for c in chars.by_ref() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(text);
+                }
+            },
+            _ => {},
+        }
+        text.push(c);
+    }
+    Err(ParsePolynomialError::UnclosedParenthesis)
+}
+
+fn coefficient<R: FromStr>(text: &str) -> Result<R, ParsePolynomialError> {
+    text.trim().parse().map_err(|_| ParsePolynomialError::InvalidCoefficient(text.to_string()))
+}
+
+/// A single unsigned term in the variable `var`, as `(degree, coefficient)`.
+fn term<R: Ring + FromStr>(chars: &mut Peekable<Chars<'_>>, var: char) -> Result<(usize, R), ParsePolynomialError>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    skip_whitespace(chars);
+    let coeff = match chars.peek() {
+        Some('(') => {
+            chars.next();
+            Some(coefficient(&parenthesised(chars)?)?)
+        },
+        Some(c) if c.is_ascii_digit() => Some(coefficient(&digits(chars))?),
+        _ => None,
+    };
+    skip_whitespace(chars);
+    // After an explicit `*` the variable is required.
+    let star = coeff.is_some() && chars.next_if_eq(&'*').is_some();
+    skip_whitespace(chars);
+    let degree = match chars.peek().copied() {
+        Some(c) if c.is_alphabetic() => {
+            chars.next();
+            if c != var {
+                return Err(ParsePolynomialError::WrongVariable { expected: var, found: c });
+            }
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'^').is_some() {
+                skip_whitespace(chars);
+                let exponent = digits(chars);
+                exponent.parse().map_err(|_| ParsePolynomialError::InvalidExponent(exponent))?
+            } else {
+                1
+            }
+        },
+        Some(c) if star || coeff.is_none() => return Err(ParsePolynomialError::UnexpectedCharacter(c)),
+        None if star || coeff.is_none() => return Err(ParsePolynomialError::UnexpectedEnd),
+        _ => 0,
+    };
+    Ok((degree, coeff.unwrap_or_else(R::one)))
+}
+
+// FromStr implementation
+impl<R: Ring + FromStr, const VAR: Indeterminate> FromStr for Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars().peekable();
+        skip_whitespace(&mut chars);
+        if chars.peek().is_none() {
+            return Err(ParsePolynomialError::Empty);
+        }
+        let mut vals: HashMap<usize, R> = HashMap::new();
+        let mut negate = chars.next_if_eq(&'-').is_some();
+        loop {
+            let (degree, coeff) = term::<R>(&mut chars, VAR.name)?;
+            let coeff = if negate { -&coeff } else { coeff };
+            *vals.entry(degree).or_insert_with(R::zero) += coeff;
+            skip_whitespace(&mut chars);
+            negate = match chars.next() {
+                Some('+') => false,
+                Some('-') => true,
+                Some(c) => return Err(ParsePolynomialError::UnexpectedCharacter(c)),
+                None => break,
+            };
+        }
+        Ok(Self::new(&vals))
+    }
+}
+
+/// The character starting at byte `i` of the UTF-8 `text`, and the byte just past it.
+const fn char_at(text: &[u8], i: usize) -> (char, usize) {
+    let (len, mut code) = match text[i] {
+        b if b < 0x80 => (1, b as u32),
+        b if b < 0xe0 => (2, (b & 0x1f) as u32),
+        b if b < 0xf0 => (3, (b & 0x0f) as u32),
+        b => (4, (b & 0x07) as u32),
+    };
+    let mut k = 1;
+    while k < len {
+        code = (code << 6) | (text[i + k] & 0x3f) as u32;
+        k += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => (c, i + len),
+        None => panic!("A str is valid UTF-8"),
+    }
+}
+
+/// The first byte from `i` on that does not start a whitespace character.
+const fn skip_whitespace_from(text: &[u8], mut i: usize) -> usize {
+    while i < text.len() {
+        let (c, next) = char_at(text, i);
+        if !c.is_whitespace() {
+            break;
+        }
+        i = next;
+    }
+    i
+}
+
+/// The first byte from `i` on that is not an ASCII digit.
+const fn skip_digits_from(text: &[u8], mut i: usize) -> usize {
+    while i < text.len() && text[i].is_ascii_digit() {
+        i += 1;
+    }
+    i
+}
+
+/// Checks the text of a `poly!` literal at compile time, following the grammar of `str::parse`: the terms and their
+/// signs, the parentheses, the exponents, and that the only variable is `var`. Only the coefficients are left
+/// unchecked, since only the coefficient ring can parse them.
+///
+/// `char::is_alphabetic` is not available here, so every ASCII letter and non-ASCII character is taken for a variable.
+/// That only changes which error a bad literal gets, never whether it is rejected.
+#[doc(hidden)]
+pub const fn check_literal(text: &str, var: char) -> Result<(), &'static str> {
+    let text = text.as_bytes();
+    let mut i = skip_whitespace_from(text, 0);
+    if i == text.len() {
+        return Err("the literal is empty");
+    }
+    if text[i] == b'-' {
+        i += 1;
+    }
+    loop {
+        i = skip_whitespace_from(text, i);
+        let mut coeff = false;
+        if i < text.len() && text[i] == b'(' {
+            let mut depth = 1;
+            i += 1;
+            while depth > 0 {
+                if i == text.len() {
+                    return Err("a parenthesised coefficient is never closed");
+                }
+                match text[i] {
+                    b'(' => depth += 1,
+                    b')' => depth -= 1,
+                    _ => {},
+                }
+                i += 1;
+            }
+            coeff = true;
+        } else if i < text.len() && text[i].is_ascii_digit() {
+            i = skip_digits_from(text, i);
+            coeff = true;
+        }
+        i = skip_whitespace_from(text, i);
+        // After an explicit `*` the variable is required.
+        let star = coeff && i < text.len() && text[i] == b'*';
+        if star {
+            i += 1;
+        }
+        i = skip_whitespace_from(text, i);
+        if i == text.len() {
+            if star || !coeff {
+                return Err("the literal ends where a term is expected");
+            }
+            return Ok(());
+        }
+        let (c, next) = char_at(text, i);
+        if c == var {
+            i = skip_whitespace_from(text, next);
+            if i < text.len() && text[i] == b'^' {
+                i = skip_whitespace_from(text, i + 1);
+                let start = i;
+                let mut exponent: usize = 0;
+                while i < text.len() && text[i].is_ascii_digit() {
+                    exponent = match exponent.checked_mul(10) {
+                        Some(shifted) => match shifted.checked_add((text[i] - b'0') as usize) {
+                            Some(exponent) => exponent,
+                            None => return Err("an exponent does not fit in a usize"),
+                        },
+                        None => return Err("an exponent does not fit in a usize"),
+                    };
+                    i += 1;
+                }
+                if i == start {
+                    return Err("an exponent is missing");
+                }
+            }
+        } else if c.is_ascii_alphabetic() || !c.is_ascii() {
+            return Err("the literal uses a variable other than the polynomial's own");
+        } else if star || !coeff {
+            return Err("the literal has an unexpected character");
+        }
+        i = skip_whitespace_from(text, i);
+        if i == text.len() {
+            return Ok(());
+        }
+        match text[i] {
+            b'+' | b'-' => i += 1,
+            _ => return Err("the literal has an unexpected character"),
+        }
+    }
+}
+
+/// Builds the polynomial of a `poly!` literal, whose text `TEXT` is checked against `VAR` at compile time. Only its
+/// coefficients are parsed at runtime, panicking with the reason if the coefficient ring rejects one.
+#[doc(hidden)]
+pub fn literal<R: Ring + FromStr, const VAR: Indeterminate, const TEXT: &'static str>() -> Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R>
+{
+    const {
+        if let Err(reason) = check_literal(TEXT, VAR.name) {
+            panic!("{}", reason);
+        }
+    };
+    match TEXT.parse() {
+        Ok(poly) => poly,
+        Err(error) => panic!("Invalid polynomial literal \"{TEXT}\": {error}"),
+    }
+}
+
+/// A polynomial literal, with its coefficient ring and variable taken from the expected type.
+///
+/// The polynomial is written either as a string, as in `poly!("3X^4 - X + 2")`, or directly as tokens, as in
+/// `poly!(3*X^4 - X + 2)`. Both follow the grammar of `str::parse`. A malformed literal, or one using a variable other
+/// than the polynomial's own, fails to compile. The coefficients can only be parsed by the coefficient ring at
+/// runtime, so a coefficient it rejects, such as `(T + 1)` in a polynomial over `IMod<5>`, panics.
+///
+/// For example `let f: Polynomial<IMod<5>, X> = poly!(3*X^4 - X + 2);` is `3X^4 + 4X + 2`.
+#[macro_export]
+macro_rules! poly {
+    ($text:literal) => {
+        $crate::polynomial::parse::literal::<_, _, { concat!($text) }>()
+    };
+    ($($tokens:tt)+) => {
+        $crate::polynomial::parse::literal::<_, _, { stringify!($($tokens)+) }>()
+    };
+}
+
+#[cfg(test)]
+mod test;
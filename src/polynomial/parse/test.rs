@@ -0,0 +1,97 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::IMod, T, X, Y};
+
+type Poly<const P: u64> = Polynomial<IMod<P>, X>;
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+
+fn from_coeffs<const P: u64>(coeffs: &[u128]) -> Poly<P> {
+    Poly::from_coeffs(&coeffs.iter().map(|&x| IMod::new(x)).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!("3X^4 - X + 2".parse::<Poly<5>>(), Ok(from_coeffs(&[2, 4, 0, 0, 3])));
+    assert_eq!("-X".parse::<Poly<5>>(), Ok(from_coeffs(&[0, 4])));
+    assert_eq!("  7 * X ^ 2+X  ".parse::<Poly<5>>(), Ok(from_coeffs(&[0, 1, 2])));
+    assert_eq!("X^12".parse::<Poly<5>>(), Ok(Poly::indeterminant_power(12)));
+    // Repeated powers are summed, and may cancel.
+    assert_eq!("X + X + 3X".parse::<Poly<5>>(), Ok(Poly::zero()));
+    assert_eq!("0".parse::<Poly<5>>(), Ok(Poly::zero()));
+    // Coefficients are reduced.
+    assert_eq!("123456789X".parse::<Poly<7>>(), Ok(from_coeffs(&[0, 123456789 % 7])));
+    assert_eq!("Y^2 + 1".parse::<Polynomial<IMod<3>, Y>>(), Ok(Polynomial::from_coeffs(&[IMod::one(), IMod::zero(), IMod::one()])));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!("Y + 1".parse::<Poly<5>>(), Err(ParsePolynomialError::WrongVariable { expected: 'X', found: 'Y' }));
+    assert_eq!("  ".parse::<Poly<5>>(), Err(ParsePolynomialError::Empty));
+    assert_eq!("X +".parse::<Poly<5>>(), Err(ParsePolynomialError::UnexpectedEnd));
+    assert_eq!("3*".parse::<Poly<5>>(), Err(ParsePolynomialError::UnexpectedEnd));
+    assert_eq!("X^".parse::<Poly<5>>(), Err(ParsePolynomialError::InvalidExponent(String::new())));
+    assert_eq!("X 2".parse::<Poly<5>>(), Err(ParsePolynomialError::UnexpectedCharacter('2')));
+    assert_eq!("(1 + 2".parse::<Poly<5>>(), Err(ParsePolynomialError::UnclosedParenthesis));
+    assert_eq!("(T)X".parse::<Poly<5>>(), Err(ParsePolynomialError::InvalidCoefficient("T".to_string())));
+}
+
+#[test]
+fn test_parse_round_trip() {
+    let f = from_coeffs::<11>(&[10, 0, 1, 5, 0, 0, 0, 1]);
+    assert_eq!(f.to_string().parse::<Poly<11>>(), Ok(f));
+    let a = F9::new(&[IMod::new(1), IMod::new(2)]);
+    let f = Polynomial::<F9, X>::from_coeffs(&[F9::one(), a, F9::zero(), F9::generator()]);
+    assert_eq!(f.to_string().parse(), Ok(f));
+}
+
+#[test]
+fn test_parse_field_elements() {
+    assert_eq!("-3".parse::<IMod<7>>(), Ok(IMod::new(4)));
+    assert_eq!("T + 2".parse::<F9>(), Ok(F9::new(&[IMod::new(2), IMod::new(1)])));
+    // T^2 = T + 1 modulo T^2 + 2T + 2 over F3.
+    assert_eq!("T^2".parse::<F9>(), Ok(F9::new(&[IMod::new(1), IMod::new(1)])));
+    assert!("X".parse::<F9>().is_err());
+}
+
+#[test]
+fn test_poly_macro() {
+    let f: Poly<5> = poly!("3X^4 - X + 2");
+    assert_eq!(f, from_coeffs(&[2, 4, 0, 0, 3]));
+    let g: Poly<5> = poly!(3*X^4 - X + 2);
+    assert_eq!(g, f);
+    let one: Poly<5> = poly!(1);
+    assert_eq!(one, Poly::one());
+    let h: Polynomial<F9, X> = poly!(X^2 + (T + 1)*X + (2T));
+    assert_eq!(h.coeff(1), F9::new(&[IMod::new(1), IMod::new(1)]));
+}
+
+#[test]
+fn test_poly_macro_literals_are_checked_at_compile_time() {
+    // `poly!` evaluates `check_literal` in a const block, so each rejected text here fails to compile as a literal.
+    const { assert!(check_literal(stringify!(3*X^4 - X + 2), 'X').is_ok()) };
+    const { assert!(check_literal(stringify!(X^2 + (T + 1)*X + (2T)), 'X').is_ok()) };
+    const { assert!(check_literal(stringify!(Y + 1), 'X').is_err()) };
+    const { assert!(check_literal("Y^2 + 1", 'Y').is_ok()) };
+    const { assert!(check_literal("X + λ", 'X').is_err()) };
+    const { assert!(check_literal("λ^2 - 1", 'λ').is_ok()) };
+    const { assert!(check_literal("X^99999999999999999999999", 'X').is_err()) };
+    assert_eq!(check_literal("Y + 1", 'X'), Err("the literal uses a variable other than the polynomial's own"));
+}
+
+#[test]
+fn test_check_literal_agrees_with_parse() {
+    // With plain integer coefficients, the compile-time check accepts exactly what `str::parse` does.
+    for text in [
+        "3X^4 - X + 2", "-X", "  7 * X ^ 2+X  ", "X^12", "0", "X + X", "(2)X^3", "(1)", "3 X",
+        "Y + 1", "  ", "", "X +", "3*", "X^", "X 2", "(1 + 2", "--X", "X-", "*X", "3**X", "X^-1", "2 ^ 3", "X^2^3",
+        "3 4", "+X", "x", "X*2",
+    ] {
+        assert_eq!(check_literal(text, 'X').is_ok(), text.parse::<Poly<5>>().is_ok(), "{text:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid coefficient \"T\"")]
+fn test_poly_macro_invalid_coefficient() {
+    // Coefficients are only parsed at runtime, by the coefficient ring.
+    let _: Poly<5> = poly!((T)*X + 1);
+}
@@ -0,0 +1,77 @@
+//! Roots of polynomials over finite fields.
+//!
+//! The roots of `f` in `GF(q)` are those of `gcd(f, X^q - X)`, which is a product of distinct linear factors. Those
+//! are separated by Cantor-Zassenhaus equal-degree splitting with degree one, rather than by trying every element of
+//! the field as a Chien search does.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{FiniteField, IndexedField, Indeterminate};
+
+use super::{dense::DensePolynomial, division::Modulus, factor::{equal_degree, pow_size, SplitMix}, Polynomial};
+
+/// The distinct roots of a non-zero `f`, in no particular order, drawing the random polynomials for splitting from
+/// `random`.
+pub fn distinct_roots<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, random: &mut impl FnMut() -> R) -> Vec<R>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    if f.deg().expect("Every element is a root of the zero polynomial") == 0 {
+        return Vec::new();
+    }
+    let x = DensePolynomial::indeterminant_power(1);
+    let x_q = pow_size(&Modulus::new(f), &x);
+    let linear = f.gcd(&(&x_q - &x));
+    if linear.deg() == Some(0) {
+        return Vec::new();
+    }
+    equal_degree(&linear, 1, random).iter().map(|g| -&g.coeff(0)).collect()
+}
+
+/// The multiplicity of `root` as a root of the non-zero `f`, by repeated division by `X - root`.
+pub fn multiplicity<R: FiniteField, const VAR: Indeterminate>(f: &DensePolynomial<R, VAR>, root: &R) -> u32
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    let linear = DensePolynomial::new(vec![-root, R::one()]);
+    let mut f = f.clone();
+    let mut count = 0;
+    loop {
+        let (quotient, remainder) = f.div_rem(&linear);
+        if remainder.deg().is_some() {
+            return count;
+        }
+        f = quotient;
+        count += 1;
+    }
+}
+
+impl<R: IndexedField, const VAR: Indeterminate> Polynomial<R, VAR>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    /// Every root in the coefficient field with its multiplicity, sorted by `IndexedField::index`. Panics on the zero
+    /// polynomial.
+    pub fn roots(&self) -> Vec<(R, u32)> {
+        self.roots_in::<R>()
+    }
+
+    /// Every root in the extension `F` of the coefficient field with its multiplicity, sorted by
+    /// `IndexedField::index`. For example `roots_in::<GF<P, N, Y>>()` finds the roots of a polynomial over `IMod<P>`
+    /// in `GF(P^N)`. The random choices come from a fixed seed, so the result is reproducible. Panics on the zero
+    /// polynomial.
+    pub fn roots_in<F: IndexedField + From<R>>(&self) -> Vec<(F, u32)>
+    where
+        for<'a, 'b> &'a F : Add<&'b F, Output = F> + Mul<&'b F, Output = F> + Sub<&'b F, Output = F> + Neg<Output = F> + Div<&'b F, Output = F>
+    {
+        let f: DensePolynomial<F, VAR> = DensePolynomial::new(DensePolynomial::from(self).coeffs().iter().map(|&x| F::from(x)).collect());
+        // The random coefficients are drawn from the non-zero elements, whose number fits in a `u128` for every field.
+        let mut rng = SplitMix(F::group_order() as u64);
+        let mut roots = distinct_roots(&f, &mut || F::from_index(rng.below(F::group_order()) + 1));
+        roots.sort_by_key(F::index);
+        roots.into_iter().map(|root| (root, multiplicity(&f, &root))).collect()
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,71 @@
+use super::*;
+use crate::{galois::GF, modulo_ints::IMod, Ring, T, X};
+
+type F4 = GF<2, 2, T, {&[1, 1, 1]}>;
+type F9 = GF<3, 2, T, {&[2, 2, 1]}>;
+
+fn poly<const P: u64>(coeffs: &[u128]) -> Polynomial<IMod<P>, X> {
+    Polynomial::from_coeffs(&coeffs.iter().map(|&x| IMod::new(x)).collect::<Vec<_>>())
+}
+
+/// The monic polynomial with the given roots, repeated by multiplicity.
+fn from_roots<R: FiniteField>(roots: &[(R, u32)]) -> Polynomial<R, X>
+where
+    for<'a, 'b> &'a R : Add<&'b R, Output = R> + Mul<&'b R, Output = R> + Sub<&'b R, Output = R> + Neg<Output = R> + Div<&'b R, Output = R>
+{
+    roots.iter().fold(Polynomial::one(), |product, (root, m)| {
+        let linear = Polynomial::from_coeffs(&[-root, R::one()]);
+        (0..*m).fold(product, |product, _| &product * &linear)
+    })
+}
+
+#[test]
+fn test_roots_over_prime_field() {
+    // (X - 1)^2 (X - 3)(X^2 + 1) over F7, where X^2 + 1 has no roots.
+    let f = &from_roots(&[(IMod::<7>::new(1), 2), (IMod::new(3), 1)]) * &poly(&[1, 0, 1]);
+    assert_eq!((&f * IMod::new(4)).roots(), vec![(IMod::new(1), 2), (IMod::new(3), 1)]);
+    assert_eq!(poly::<7>(&[1, 0, 1]).roots(), vec![]);
+    assert_eq!(poly::<7>(&[5]).roots(), vec![]);
+    // X^p - X vanishes on the whole field.
+    let f = &Polynomial::<IMod<13>, X>::indeterminant_power(13) - &Polynomial::indeterminant_power(1);
+    assert_eq!(f.roots(), (0..13).map(|x| (IMod::new(x), 1)).collect::<Vec<_>>());
+    // Many roots in a large field.
+    const P: u64 = 998_244_353;
+    let roots: Vec<(IMod<P>, u32)> = vec![(IMod::new(0), 1), (IMod::new(5), 3), (IMod::new(12_345), 1), (IMod::new(P as u128 - 1), 2)];
+    assert_eq!(from_roots(&roots).roots(), roots);
+    // A multiplicity equal to the characteristic.
+    let f = &from_roots(&[(IMod::<3>::new(2), 3)]) * &poly(&[1, 0, 1]);
+    assert_eq!(f.roots(), vec![(IMod::new(2), 3)]);
+}
+
+#[test]
+fn test_roots_over_extension_fields() {
+    let roots: Vec<(F9, u32)> = vec![(F9::from_index(1), 2), (F9::from_index(4), 1), (F9::from_index(8), 5)];
+    assert_eq!(from_roots(&roots).roots(), roots);
+    // X^4 - X over GF(4) vanishes on every element, which exercises the characteristic 2 trace.
+    let f = &Polynomial::<F4, X>::indeterminant_power(4) - &Polynomial::indeterminant_power(1);
+    assert_eq!(f.roots(), F4::elements().map(|x| (x, 1)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_roots_in_extension() {
+    // X^2 + 1 is irreducible over F3 and splits in GF(9) into conjugate roots.
+    let f = poly::<3>(&[1, 0, 1]);
+    let roots = f.roots_in::<F9>();
+    assert_eq!(roots.len(), 2);
+    for (root, m) in &roots {
+        assert_eq!(*m, 1);
+        assert_eq!(*root * *root + F9::one(), F9::zero());
+    }
+    assert_eq!(roots[0].0.pth_root(), roots[1].0);
+    // X^2 + X + 1 over F2 has no roots in F2 but splits over GF(4).
+    let f = poly::<2>(&[1, 1, 1]);
+    assert_eq!(f.roots(), vec![]);
+    assert_eq!(f.roots_in::<F4>().len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "zero polynomial")]
+fn test_roots_of_zero() {
+    Polynomial::<IMod<5>, X>::zero().roots();
+}
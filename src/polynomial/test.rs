@@ -175,6 +175,25 @@ fn test_polynomial_division_exact() {
     assert_eq!(dividend, reconstructed);
 }
 
+#[test]
+fn test_polynomial_checked_division() {
+    type F7 = IMod<7>;
+    let dividend: Polynomial<F7, X> = poly_from_coeffs(&[(0, F7::new(6)), (3, F7::new(1))]);
+    let divisor: Polynomial<F7, X> = poly_from_coeffs(&[(0, F7::new(5)), (1, F7::new(1))]);
+    assert_eq!(dividend.checked_div_rem(&divisor), Ok(dividend.div_rem(&divisor)));
+    assert_eq!(dividend.checked_div(&divisor), Ok(&dividend / &divisor));
+    assert_eq!(dividend.checked_rem(&divisor), Ok(&dividend % &divisor));
+    assert_eq!(dividend.checked_div(&Polynomial::zero()), Err(crate::Error::DivisionByZero));
+    assert_eq!(dividend.checked_rem(&Polynomial::zero()), Err(crate::Error::DivisionByZero));
+    let vals = [(0, F7::new(6)), (2, F7::zero()), (3, F7::new(1))].into_iter().collect();
+    assert_eq!(Polynomial::<F7, X>::try_new(&vals), Ok(dividend.clone()));
+    // Only non-zero constants are units.
+    let constant: Polynomial<F7, X> = poly_from_coeffs(&[(0, F7::new(3))]);
+    assert_eq!(constant.try_inverse(), Ok(poly_from_coeffs(&[(0, F7::new(5))])));
+    assert_eq!(divisor.try_inverse(), Err(crate::Error::NonUnit));
+    assert_eq!(Polynomial::<F7, X>::zero().try_inverse(), Err(crate::Error::DivisionByZero));
+}
+
 #[test]
 fn test_polynomial_scalar_division() {
     type F5 = IMod<5>;
@@ -201,3 +220,112 @@ fn test_division_by_zero_polynomial() {
     
     let _result = &dividend / &zero_divisor; // Should panic
 }
+
+#[test]
+fn test_coefficient_access() {
+    // 3X^4 + 2X over IMod<5>
+    let p: Polynomial<IMod<5>, X> = poly_from_coeffs(&[(4, IMod::new(3)), (1, IMod::new(2))]);
+    assert_eq!(p.coeff(4), IMod::new(3));
+    assert_eq!(p.coeff(0), IMod::zero());
+    assert_eq!(p.coeff(100), IMod::zero());
+    assert_eq!(p.terms().collect::<Vec<_>>(), vec![(1, &IMod::new(2)), (4, &IMod::new(3))]);
+    assert_eq!(Polynomial::<IMod<5>, X>::zero().terms().count(), 0);
+    assert_eq!(Polynomial::from_coeffs(&[IMod::zero(), IMod::new(2), IMod::zero(), IMod::zero(), IMod::new(3)]), p);
+}
+
+#[test]
+fn test_derivative() {
+    // d/dX (X^5 + 3X^2 + 4) = 5X^4 + 6X = 6X over IMod<5>
+    let p: Polynomial<IMod<5>, X> = poly_from_coeffs(&[(5, IMod::one()), (2, IMod::new(3)), (0, IMod::new(4))]);
+    assert_eq!(p.derivative(), poly_from_coeffs(&[(1, IMod::new(1))]));
+    assert_eq!(Polynomial::<IMod<5>, X>::one().derivative(), Polynomial::zero());
+    let dense = dense::DensePolynomial::from(&p);
+    assert_eq!(Polynomial::from(dense.derivative()), p.derivative());
+    // The product rule.
+    let q: Polynomial<IMod<7>, X> = poly_from_coeffs(&[(3, IMod::new(2)), (1, IMod::one()), (0, IMod::new(5))]);
+    let r: Polynomial<IMod<7>, X> = poly_from_coeffs(&[(2, IMod::one()), (0, IMod::new(3))]);
+    assert_eq!((&q * &r).derivative(), &(&q.derivative() * &r) + &(&q * &r.derivative()));
+}
+
+#[test]
+fn test_reverse_truncate_and_shift() {
+    // 1 + 2X + 3X^3 over IMod<7>
+    let p: Polynomial<IMod<7>, X> = poly_from_coeffs(&[(0, IMod::one()), (1, IMod::new(2)), (3, IMod::new(3))]);
+    assert_eq!(p.reverse(3), poly_from_coeffs(&[(3, IMod::one()), (2, IMod::new(2)), (0, IMod::new(3))]));
+    assert_eq!(p.reverse(5), poly_from_coeffs(&[(5, IMod::one()), (4, IMod::new(2)), (2, IMod::new(3))]));
+    assert_eq!(p.reverse(1), poly_from_coeffs(&[(1, IMod::one()), (0, IMod::new(2))]));
+    assert_eq!(p.reverse(3).reverse(3), p);
+    assert_eq!(p.truncate(2), poly_from_coeffs(&[(0, IMod::one()), (1, IMod::new(2))]));
+    assert_eq!(p.truncate(0), Polynomial::zero());
+    assert_eq!(p.truncate(10), p);
+    assert_eq!(p.shift_left(2), &p * &Polynomial::indeterminant_power(2));
+    assert_eq!(p.shift_right(1), poly_from_coeffs(&[(0, IMod::new(2)), (2, IMod::new(3))]));
+    assert_eq!(p.shift_left(4).shift_right(4), p);
+    assert_eq!(p.shift_right(4), Polynomial::zero());
+}
+
+#[test]
+fn test_pow_mod() {
+    type F5 = IMod<5>;
+    // Modulo X^2 + 3 we have X^2 = 2, so X^5 = X * 2^2 = 4X
+    let modulus: Polynomial<F5, X> = poly_from_coeffs(&[(0, F5::new(3)), (2, F5::new(1))]);
+    let x: Polynomial<F5, X> = Polynomial::indeterminant_power(1);
+    let expected: Polynomial<F5, X> = poly_from_coeffs(&[(1, F5::new(4))]);
+    assert_eq!(x.pow_mod(5, &modulus), expected);
+    assert_eq!(x.pow_mod(0, &modulus), Polynomial::one());
+}
+
+#[test]
+fn test_is_irreducible() {
+    type F2 = IMod<2>;
+    type F3 = IMod<3>;
+    type F5 = IMod<5>;
+    // X^2 + 1 is irreducible over F3 but not over F5, where 2^2 = -1.
+    let over_f3: Polynomial<F3, X> = poly_from_coeffs(&[(0, F3::new(1)), (2, F3::new(1))]);
+    let over_f5: Polynomial<F5, X> = poly_from_coeffs(&[(0, F5::new(1)), (2, F5::new(1))]);
+    assert!(over_f3.is_irreducible());
+    assert!(!over_f5.is_irreducible());
+
+    // X^4 + X + 1 is irreducible over F2.
+    let p: Polynomial<F2, X> = poly_from_coeffs(&[(0, F2::new(1)), (1, F2::new(1)), (4, F2::new(1))]);
+    assert!(p.is_irreducible());
+
+    // X^4 + X^2 + 1 = (X^2 + X + 1)^2 has no roots in F2 but is still reducible.
+    let p: Polynomial<F2, X> = poly_from_coeffs(&[(0, F2::new(1)), (2, F2::new(1)), (4, F2::new(1))]);
+    assert!(!p.is_irreducible());
+
+    // Linear polynomials are irreducible, constants are not.
+    let linear: Polynomial<F5, X> = poly_from_coeffs(&[(0, F5::new(3)), (1, F5::new(2))]);
+    assert!(linear.is_irreducible());
+    assert!(!Polynomial::<F5, X>::one().is_irreducible());
+    assert!(!Polynomial::<F5, X>::zero().is_irreducible());
+}
+
+#[test]
+fn test_primitive_polynomials() {
+    type F2 = IMod<2>;
+    type F3 = IMod<3>;
+    // There are phi(2^4 - 1)/4 = 2 primitive quartics over F2: X^4 + X + 1 and X^4 + X^3 + 1.
+    let quartics: Vec<Polynomial<F2, X>> = Polynomial::primitive_polynomials(4).collect();
+    assert_eq!(quartics, vec![
+        poly_from_coeffs(&[(0, F2::new(1)), (1, F2::new(1)), (4, F2::new(1))]),
+        poly_from_coeffs(&[(0, F2::new(1)), (3, F2::new(1)), (4, F2::new(1))]),
+    ]);
+    // phi(3^3 - 1)/3 = 4 primitive cubics over F3.
+    assert_eq!(Polynomial::<F3, X>::primitive_polynomials(3).count(), 4);
+    // X^4 + X^3 + X^2 + X + 1 is irreducible over F2 but X has order 5 modulo it.
+    let p: Polynomial<F2, X> = poly_from_coeffs(&[(0, F2::new(1)), (1, F2::new(1)), (2, F2::new(1)), (3, F2::new(1)), (4, F2::new(1))]);
+    assert!(p.is_irreducible());
+    assert!(!p.is_primitive());
+}
+
+#[test]
+fn test_find_primitive() {
+    type F2 = IMod<2>;
+    // The first primitive polynomial found of degree 7 is the trinomial X^7 + X + 1.
+    let p: Polynomial<F2, X> = Polynomial::find_primitive(7);
+    assert_eq!(p, poly_from_coeffs(&[(0, F2::new(1)), (1, F2::new(1)), (7, F2::new(1))]));
+    let p: Polynomial<IMod<7>, X> = Polynomial::find_primitive(3);
+    assert_eq!(p.deg(), Some(3));
+    assert!(p.is_primitive());
+}
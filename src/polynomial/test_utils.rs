@@ -0,0 +1,31 @@
+//! Fixtures shared by the tests of the polynomial modules.
+
+use crate::{modulo_ints::IMod, Ring};
+
+/// 998244353 = 119 * 2^23 + 1 supports transforms of length up to 2^23.
+pub const NTT_PRIME: u64 = 998_244_353;
+
+/// Deterministic pseudo-random values below `2^31` from a linear congruential generator.
+pub fn random_values(len: usize, seed: u64) -> Vec<u128> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            (state >> 33) as u128
+        })
+        .collect()
+}
+
+/// Deterministic pseudo-random residues, reducing `random_values`.
+pub fn random_imod<const P: u64>(len: usize, seed: u64) -> Vec<IMod<P>> {
+    random_values(len, seed).into_iter().map(IMod::new).collect()
+}
+
+/// Deterministic pseudo-random coefficients of a polynomial of degree `len - 1`, with a non-zero leading one.
+pub fn random_coeffs<const P: u64>(len: usize, seed: u64) -> Vec<IMod<P>> {
+    let mut coeffs = random_imod(len, seed);
+    if let Some(leading) = coeffs.last_mut() && *leading == IMod::zero() {
+        *leading = IMod::one();
+    }
+    coeffs
+}
@@ -51,6 +51,164 @@ pub const fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
     result as u64
 }
 
+/// Compute a*b mod m without overflowing, for any u128 modulus.
+pub const fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (a, mut b) = (a % m, b % m);
+    if m <= u64::MAX as u128 {
+        return (a * b) % m;
+    }
+    // Double-and-add, keeping every intermediate value below m.
+    let mut result = 0u128;
+    let mut addend = a;
+    while b > 0 {
+        if b % 2 == 1 {
+            result = add_mod(result, addend, m);
+        }
+        addend = add_mod(addend, addend, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// Compute a+b mod m for a, b < m without overflowing.
+const fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Compute a^b mod m for any u128 modulus using fast modular exponentiation
+pub const fn mod_pow_u128(base: u128, exp: u128, modulus: u128) -> u128 {
+    if modulus == 1 {
+        return 0;
+    }
+    let mut result = 1u128;
+    let mut base = base % modulus;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        exp >>= 1;
+        base = mul_mod(base, base, modulus);
+    }
+    result
+}
+
+/// Miller-Rabin primality test.
+/// The witnesses used make this deterministic for all n < 3.3 * 10^24, and it is probabilistic (though with
+/// vanishingly small error) beyond that.
+pub const fn is_prime(n: u128) -> bool {
+    const WITNESSES: [u128; 20] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n.is_multiple_of(WITNESSES[i]) {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    // n - 1 = d * 2^s with d odd
+    let mut d = n - 1;
+    let mut s = 0;
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    'witness: while i < WITNESSES.len() {
+        let mut x = mod_pow_u128(WITNESSES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+/// Find a non-trivial factor of an odd composite n with Pollard's rho algorithm (Brent's variant).
+fn pollard_rho(n: u128) -> u128 {
+    let mut c = 1u128;
+    loop {
+        let f = |x: u128| add_mod(mul_mod(x, x, n), c, n);
+        let (mut x, mut y, mut g) = (2u128, 2u128, 1u128);
+        while g == 1 {
+            x = f(x);
+            y = f(f(y));
+            g = gcd(x.abs_diff(y), n);
+        }
+        if g != n {
+            return g;
+        }
+        // The cycle closed without splitting n, so retry with a different polynomial.
+        c += 1;
+    }
+}
+
+/// Greatest common divisor of two unsigned integers.
+pub const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// The prime factorisation of n as (prime, exponent) pairs in increasing order of prime.
+/// Small factors are removed by trial division and the rest are split with Pollard's rho.
+pub fn factorize(mut n: u128) -> Vec<(u128, u32)> {
+    let mut primes = Vec::new();
+    let mut d = 2;
+    while d < 1000 && d * d <= n {
+        while n.is_multiple_of(d) {
+            primes.push(d);
+            n /= d;
+        }
+        d += 1;
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime(m) {
+            primes.push(m);
+        } else {
+            let factor = pollard_rho(m);
+            stack.push(factor);
+            stack.push(m / factor);
+        }
+    }
+    primes.sort_unstable();
+    let mut factors: Vec<(u128, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// The distinct prime factors of n, in increasing order.
+pub fn distinct_prime_factors(n: u128) -> Vec<u128> {
+    factorize(n).into_iter().map(|(p, _)| p).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +246,44 @@ mod tests {
         assert_eq!(mod_pow(3, 4, 7), 4); // 3^4 = 81, 81 % 7 = 4
         assert_eq!(mod_pow(5, 0, 13), 1); // Any number to power 0 is 1
     }
+
+    #[test]
+    fn test_distinct_prime_factors() {
+        assert_eq!(distinct_prime_factors(1), Vec::<u128>::new());
+        assert_eq!(distinct_prime_factors(12), vec![2, 3]);
+        assert_eq!(distinct_prime_factors(97), vec![97]);
+        assert_eq!(distinct_prime_factors(2 * 2 * 5 * 7 * 7), vec![2, 5, 7]);
+    }
+
+    #[test]
+    fn test_mul_mod() {
+        let m = u128::MAX - 158; // the largest prime below 2^128
+        assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+        assert_eq!(mul_mod(3, 5, 7), 1);
+    }
+
+    #[test]
+    fn test_is_prime() {
+        assert!(!is_prime(0));
+        assert!(!is_prime(1));
+        assert!(is_prime(2));
+        assert!(is_prime(97));
+        assert!(!is_prime(561)); // Carmichael number
+        assert!(is_prime((1 << 61) - 1));
+        assert!(!is_prime(((1 << 61) - 1) * ((1 << 31) - 1)));
+        assert!(is_prime(u128::MAX - 158));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        // 2^64 + 1 = 274177 * 67280421310721
+        assert_eq!(factorize((1 << 64) + 1), vec![(274177, 1), (67280421310721, 1)]);
+        assert_eq!(factorize(((1 << 61) - 1) * ((1 << 31) - 1)), vec![((1 << 31) - 1, 1), ((1 << 61) - 1, 1)]);
+        // 3^40 - 1, the order of the multiplicative group of GF(3^40)
+        let n = 3u128.pow(40) - 1;
+        let product: u128 = factorize(n).iter().map(|&(p, e)| p.pow(e)).product();
+        assert_eq!(product, n);
+    }
 }
\ No newline at end of file